@@ -0,0 +1,1114 @@
+//! Implementation of [Scalar] for [FloatAdapter] over any [AdapterFloat]
+//!
+//! `Float` lacks the special functions and the exponent manipulation of [Scalar], which are
+//! evaluated by the complex series of the crate and by scaling in representable powers of two.
+
+use crate::{
+    distributions, div, hex, parse,
+    scalar_ref::{AdapterFloat, FloatAdapter},
+    special, ComplexScalar, Halton, NumScalar, ParseScalarError, RealScalar, Scalar,
+};
+use core::cmp::Ordering;
+use core::fmt::{self, LowerExp, UpperExp};
+use core::iter::{Product, Sum};
+use core::num::FpCategory;
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{
+    float::TotalOrder, ConstOne, ConstZero, Float, FloatConst, FromPrimitive, Num, NumCast,
+    ToPrimitive, Zero,
+};
+use rand::prelude::*;
+use rand_distr::{Exp, Gamma, StandardNormal};
+
+impl<T: LowerExp> LowerExp for FloatAdapter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: UpperExp> UpperExp for FloatAdapter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ComplexFloat + ConstZero> ConstZero for FloatAdapter<T> {
+    const ZERO: Self = FloatAdapter(T::ZERO);
+}
+
+impl<T: ComplexFloat + ConstOne> ConstOne for FloatAdapter<T> {
+    const ONE: Self = FloatAdapter(T::ONE);
+}
+
+impl<T: ComplexFloat> Num for FloatAdapter<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(FloatAdapter)
+    }
+}
+
+impl<T: ComplexFloat> Sum for FloatAdapter<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, x| sum + x)
+    }
+}
+
+impl<T: ComplexFloat> Product for FloatAdapter<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(FloatAdapter(T::one()), |product, x| product * x)
+    }
+}
+
+impl<T: ToPrimitive> ToPrimitive for FloatAdapter<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+    fn to_f32(&self) -> Option<f32> {
+        self.0.to_f32()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+impl<T: NumCast> FromPrimitive for FloatAdapter<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from(n).map(FloatAdapter)
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from(n).map(FloatAdapter)
+    }
+    fn from_f32(n: f32) -> Option<Self> {
+        T::from(n).map(FloatAdapter)
+    }
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from(n).map(FloatAdapter)
+    }
+}
+
+impl<T: NumCast> NumCast for FloatAdapter<T> {
+    fn from<P: ToPrimitive>(n: P) -> Option<Self> {
+        T::from(n).map(FloatAdapter)
+    }
+}
+
+macro_rules! impl_adapter_float {
+    (@const $($f:ident)*) => {
+        $(
+            #[inline]
+            fn $f() -> Self {
+                FloatAdapter(T::$f())
+            }
+        )*
+    };
+    (@test $($f:ident)*) => {
+        $(
+            #[inline]
+            fn $f(self) -> bool {
+                self.0.$f()
+            }
+        )*
+    };
+    (@unary $($f:ident)*) => {
+        $(
+            #[inline]
+            fn $f(self) -> Self {
+                FloatAdapter(self.0.$f())
+            }
+        )*
+    };
+    (@binary $($f:ident)*) => {
+        $(
+            #[inline]
+            fn $f(self, other: Self) -> Self {
+                FloatAdapter(self.0.$f(other.0))
+            }
+        )*
+    };
+}
+
+impl<T: Float + FloatConst> Float for FloatAdapter<T> {
+    impl_adapter_float!(@const
+        nan infinity neg_infinity neg_zero min_value min_positive_value epsilon max_value
+    );
+    impl_adapter_float!(@test
+        is_nan is_infinite is_finite is_normal is_subnormal is_sign_positive is_sign_negative
+    );
+    impl_adapter_float!(@unary
+        floor ceil round trunc fract abs signum recip sqrt exp exp2 ln log2 log10 to_degrees
+        to_radians cbrt sin cos tan asin acos atan exp_m1 ln_1p sinh cosh tanh asinh acosh atanh
+    );
+    impl_adapter_float!(@binary powf log max min hypot atan2 copysign);
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        self.0.classify()
+    }
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        FloatAdapter(self.0.mul_add(a.0, b.0))
+    }
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        FloatAdapter(self.0.powi(n))
+    }
+    #[inline]
+    #[allow(deprecated)]
+    fn abs_sub(self, other: Self) -> Self {
+        FloatAdapter(self.0.abs_sub(other.0))
+    }
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.0.sin_cos();
+        (FloatAdapter(sin), FloatAdapter(cos))
+    }
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.0.integer_decode()
+    }
+}
+
+impl<T: Float + FloatConst> FloatConst for FloatAdapter<T> {
+    impl_adapter_float!(@const
+        E FRAC_1_PI FRAC_1_SQRT_2 FRAC_2_PI FRAC_2_SQRT_PI FRAC_PI_2 FRAC_PI_3 FRAC_PI_4 FRAC_PI_6
+        FRAC_PI_8 LN_10 LN_2 LOG10_E LOG2_E PI SQRT_2 TAU LOG10_2 LOG2_10
+    );
+}
+
+impl<T: Float + FloatConst> TotalOrder for FloatAdapter<T> {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        // NaN of either sign is beyond the infinities of that sign, as in IEEE 754
+        let rank = |x: T| match (x.is_nan(), x.is_sign_negative()) {
+            (true, true) => 0,
+            (false, _) => 1,
+            (true, false) => 2,
+        };
+        let (a, b) = (self.0, other.0);
+        rank(a).cmp(&rank(b)).then_with(|| {
+            let ord = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            ord.then_with(|| b.is_sign_negative().cmp(&a.is_sign_negative()))
+        })
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: approx::AbsDiffEq<Epsilon = T>> approx::AbsDiffEq for FloatAdapter<T> {
+    type Epsilon = Self;
+    fn default_epsilon() -> Self {
+        FloatAdapter(T::default_epsilon())
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon.0)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: approx::RelativeEq<Epsilon = T>> approx::RelativeEq for FloatAdapter<T> {
+    fn default_max_relative() -> Self {
+        FloatAdapter(T::default_max_relative())
+    }
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+        self.0.relative_eq(&other.0, epsilon.0, max_relative.0)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: approx::UlpsEq<Epsilon = T>> approx::UlpsEq for FloatAdapter<T> {
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: Self, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon.0, max_ulps)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: Copy + rkyv::Archive<Archived = T>> rkyv::Archive for FloatAdapter<T> {
+    type Archived = Self;
+    type Resolver = ();
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: (), out: *mut Self) {
+        out.write(*self);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<S, T> rkyv::Serialize<S> for FloatAdapter<T>
+where
+    S: rkyv::Fallible + ?Sized,
+    T: Copy + rkyv::Archive<Archived = T>,
+{
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<D, T> rkyv::Deserialize<FloatAdapter<T>, D> for FloatAdapter<T>
+where
+    D: rkyv::Fallible + ?Sized,
+    T: Copy + rkyv::Archive<Archived = T>,
+{
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Self, D::Error> {
+        Ok(*self)
+    }
+}
+
+/// Number of mantissa digits of `T`, including the leading bit
+fn digits<T: Float>() -> i32 {
+    let (one, _, _) = T::one().integer_decode();
+    64 - one.leading_zeros() as i32
+}
+
+/// `x 2^exp`, multiplied in steps which are representable by `T`
+fn ldexp<T: Float>(mut x: T, mut exp: i32) -> T {
+    let (_, max_exp, _) = T::max_value().integer_decode();
+    let step = max_exp as i32 + digits::<T>() - 1;
+    let two = T::one() + T::one();
+    while exp > step && x.is_finite() && !x.is_zero() {
+        x = x * two.powi(step);
+        exp -= step;
+    }
+    while exp < -step && x.is_finite() && !x.is_zero() {
+        x = x * two.powi(-step);
+        exp += step;
+    }
+    x * two.powi(exp)
+}
+
+/// Mantissa in `[0.5, 1)` and exponent of non-zero finite numbers, and `(x, 0)` otherwise
+fn frexp<T: Float>(x: T) -> (T, i32) {
+    if x.is_zero() || !x.is_finite() {
+        return (x, 0);
+    }
+    let (mantissa, exp, _) = x.integer_decode();
+    let exp = exp as i32 + 64 - mantissa.leading_zeros() as i32;
+    (ldexp(x, -exp), exp)
+}
+
+/// Leading bits of `bits` scaled into `[0, 1)` as for the primitive floats
+fn unit<T: Float>(bits: u64) -> FloatAdapter<T> {
+    let digits = digits::<T>();
+    let two = T::one() + T::one();
+    FloatAdapter(T::from(bits >> (64 - digits)).unwrap() * two.powi(-digits))
+}
+
+/// Round a sample of a distribution over `f64`
+fn sample<T: Float, D: Distribution<f64>>(rng: &mut impl Rng, dist: D) -> FloatAdapter<T> {
+    FloatAdapter(T::from(rng.sample(dist)).unwrap())
+}
+
+/// Evaluate a complex special function on the real axis
+fn on_real_axis<T: AdapterFloat>(
+    f: fn(Complex<FloatAdapter<T>>) -> Complex<FloatAdapter<T>>,
+    x: FloatAdapter<T>,
+) -> FloatAdapter<T> {
+    f(Complex::new(x, FloatAdapter::ZERO)).re
+}
+
+impl<T: AdapterFloat> NumScalar for FloatAdapter<T> {
+    fn rand(rng: &mut impl Rng) -> Self {
+        unit(rng.next_u64())
+    }
+}
+
+impl<T: AdapterFloat> NumScalar for Complex<FloatAdapter<T>> {
+    fn rand(rng: &mut impl Rng) -> Self {
+        Complex::new(unit(rng.next_u64()), unit(rng.next_u64()))
+    }
+}
+
+impl<T: AdapterFloat> RealScalar for FloatAdapter<T> {}
+
+impl<T: AdapterFloat> ComplexScalar for Complex<FloatAdapter<T>> {
+    #[inline]
+    fn arg(self) -> FloatAdapter<T> {
+        Float::atan2(self.im, self.re)
+    }
+}
+
+macro_rules! impl_real {
+    ($($f:ident)*) => {
+        $(
+            #[inline]
+            fn $f(self) -> Self {
+                Float::$f(self)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_complex {
+    ($($f:ident)*) => {
+        $(
+            #[inline]
+            fn $f(self) -> Self {
+                Complex::$f(self)
+            }
+        )*
+    };
+}
+
+impl<T: AdapterFloat> Scalar for FloatAdapter<T> {
+    type Real = Self;
+    type Complex = Complex<Self>;
+    type Widened = Self;
+
+    const IS_COMPLEX: bool = false;
+    const COMPONENTS: usize = 1;
+    const I: Self::Complex = Complex::new(Self::ZERO, Self::ONE);
+    const PI: Self::Real = FloatAdapter(<T as AdapterFloat>::PI);
+    const E: Self::Real = FloatAdapter(<T as AdapterFloat>::E);
+    const EPSILON: Self::Real = FloatAdapter(<T as AdapterFloat>::EPSILON);
+
+    #[inline]
+    fn widen(self) -> Self {
+        self
+    }
+    #[inline]
+    fn narrow(w: Self) -> Self {
+        w
+    }
+    #[inline]
+    fn frexp(self) -> (Self, i32) {
+        let (m, e) = frexp(self.0);
+        (FloatAdapter(m), e)
+    }
+    #[inline]
+    fn ldexp(self, exp: i32) -> Self {
+        FloatAdapter(ldexp(self.0, exp))
+    }
+
+    #[inline]
+    fn re(&self) -> Self {
+        *self
+    }
+    #[inline]
+    fn im(&self) -> Self {
+        Self::ZERO
+    }
+    #[inline]
+    fn from_real(re: Self) -> Self {
+        re
+    }
+
+    fn lerp(self, other: Self, t: Self) -> Self {
+        // following C++'s std::lerp
+        let (zero, one) = (Self::ZERO, Self::ONE);
+        if (self <= zero && other >= zero) || (self >= zero && other <= zero) {
+            return t * other + (one - t) * self;
+        }
+        if t == one {
+            return other;
+        }
+        let x = self + t * (other - self);
+        if (t > one) == (other > self) {
+            Float::max(other, x)
+        } else {
+            Float::min(other, x)
+        }
+    }
+    fn midpoint(self, other: Self) -> Self {
+        let two = Self::real(2);
+        let lo = Self::min_positive_value() * two;
+        let hi = Self::max_value() / two;
+        let (abs_a, abs_b) = (Float::abs(self), Float::abs(other));
+        if abs_a <= hi && abs_b <= hi {
+            (self + other) / two
+        } else if abs_a < lo {
+            self + other / two
+        } else if abs_b < lo {
+            self / two + other
+        } else {
+            self / two + other / two
+        }
+    }
+
+    #[inline]
+    fn fmod(self, rhs: Self) -> Self {
+        self % rhs
+    }
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r < Self::ZERO {
+            r + Float::abs(rhs)
+        } else {
+            r
+        }
+    }
+    fn modf(self) -> (Self, Self) {
+        let int = Float::trunc(self);
+        let fract = if Float::is_infinite(self) {
+            Self::ZERO
+        } else {
+            self - int
+        };
+        (int, Float::copysign(fract, self))
+    }
+
+    fn pow(self, n: Self) -> Self {
+        Float::powf(self, n)
+    }
+    fn powi(self, n: i32) -> Self {
+        Float::powi(self, n)
+    }
+    fn powf(self, n: Self) -> Self {
+        Float::powf(self, n)
+    }
+    fn powc(self, n: Self::Complex) -> Self::Complex {
+        self.as_c().powc(n)
+    }
+    fn powf_c(self, n: Self) -> Self::Complex {
+        if self >= Self::ZERO {
+            Complex::new(Float::powf(self, n), Self::ZERO)
+        } else {
+            self.as_c().powf(n)
+        }
+    }
+
+    #[inline]
+    fn real<P: ToPrimitive>(re: P) -> Self {
+        NumCast::from(re).unwrap()
+    }
+    #[inline]
+    fn complex<P: ToPrimitive>(re: P, im: P) -> Self::Complex {
+        Complex::new(NumCast::from(re).unwrap(), NumCast::from(im).unwrap())
+    }
+    #[inline]
+    fn try_real<P: ToPrimitive>(re: P) -> Option<Self> {
+        NumCast::from(re)
+    }
+    fn parse(s: &str) -> Result<Self, ParseScalarError> {
+        parse::parse_real_scalar::<T>(s).map(FloatAdapter)
+    }
+    #[cfg(feature = "std")]
+    fn to_hex_string(&self) -> String {
+        hex::format(self.0)
+    }
+    #[cfg(feature = "std")]
+    fn to_shortest_string(&self) -> String {
+        parse::shortest(self.0)
+    }
+    fn from_hex_str(s: &str) -> Result<Self, ParseScalarError> {
+        match hex::parse_parts::<Self>(s)? {
+            (re, im) if im.is_zero() => Ok(re),
+            _ => Err(ParseScalarError::not_real()),
+        }
+    }
+    #[inline]
+    fn try_from_complex(c: Self::Complex) -> Option<Self> {
+        if c.im.is_zero() {
+            Some(c.re)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn try_complex<P: ToPrimitive>(re: P, im: P) -> Option<Self::Complex> {
+        Some(Complex::new(NumCast::from(re)?, NumCast::from(im)?))
+    }
+    #[inline]
+    fn cis(theta: Self) -> Self::Complex {
+        let (s, c) = Float::sin_cos(theta);
+        Complex::new(c, s)
+    }
+    #[inline]
+    fn i() -> Self::Complex {
+        Self::I
+    }
+    #[inline]
+    fn from_parts((re, im): (Self, Self)) -> Self::Complex {
+        Complex::new(re, im)
+    }
+    #[inline]
+    fn as_c(&self) -> Self::Complex {
+        Complex::new(*self, Self::ZERO)
+    }
+    #[inline]
+    fn mul_i(self) -> Self::Complex {
+        Complex::new(Self::ZERO, self)
+    }
+    #[inline]
+    fn div_i(self) -> Self::Complex {
+        Complex::new(Self::ZERO, -self)
+    }
+    #[inline]
+    fn conj(&self) -> Self {
+        *self
+    }
+    #[inline]
+    fn conj_mul(self, other: Self) -> Self {
+        self * other
+    }
+    #[inline]
+    fn proj(self) -> Self {
+        self
+    }
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Float::mul_add(self, a, b)
+    }
+    #[inline]
+    fn square(self) -> Self {
+        self * self
+    }
+    #[inline]
+    fn is_normal(&self) -> bool {
+        Float::is_normal(*self)
+    }
+    #[inline]
+    fn is_subnormal(&self) -> bool {
+        Float::classify(*self) == FpCategory::Subnormal
+    }
+
+    fn rand_normal(rng: &mut impl Rng) -> Self {
+        sample(rng, StandardNormal)
+    }
+    fn fill_rand(slice: &mut [Self], rng: &mut impl Rng) {
+        for x in slice {
+            *x = Self::rand(rng);
+        }
+    }
+    fn rand_cnormal(rng: &mut impl Rng) -> Self {
+        Self::rand_normal(rng)
+    }
+    fn rand_exp(rng: &mut impl Rng, lambda: Self) -> Self {
+        let lambda = lambda.to_f64().unwrap();
+        sample(
+            rng,
+            Exp::new(lambda).expect("invalid rate of exponential distribution"),
+        )
+    }
+    fn rand_gamma(rng: &mut impl Rng, shape: Self, scale: Self) -> Self {
+        let gamma = Gamma::new(shape.to_f64().unwrap(), scale.to_f64().unwrap());
+        sample(
+            rng,
+            gamma.expect("invalid parameters of gamma distribution"),
+        )
+    }
+    fn qrand(seq: &mut Halton) -> Self {
+        seq.next_point().0
+    }
+    fn rand_seeded(seed: u64, index: u64) -> Self {
+        unit(distributions::splitmix64(seed, index.wrapping_mul(2)))
+    }
+    fn rand_unit(rng: &mut impl Rng) -> Self {
+        if rng.gen() {
+            Self::ONE
+        } else {
+            -Self::ONE
+        }
+    }
+    fn rand_disk(rng: &mut impl Rng) -> Self {
+        sample(rng, rand::distributions::Uniform::new_inclusive(-1.0, 1.0))
+    }
+    fn rand_range(rng: &mut impl Rng, lo: Self, hi: Self) -> Self {
+        assert!(lo < hi, "invalid range {} <= x < {}", lo, hi);
+        // rounding may reach the upper bound, which is excluded
+        loop {
+            let x = lo + Self::rand(rng) * (hi - lo);
+            if x < hi {
+                return x;
+            }
+        }
+    }
+
+    #[inline]
+    fn add_real(self, re: Self) -> Self {
+        self + re
+    }
+    #[inline]
+    fn sub_real(self, re: Self) -> Self {
+        self - re
+    }
+    #[inline]
+    fn mul_real(self, re: Self) -> Self {
+        self * re
+    }
+    #[inline]
+    fn div_real(self, re: Self) -> Self {
+        self / re
+    }
+    #[inline]
+    fn add_complex(self, im: Self::Complex) -> Self::Complex {
+        self.as_c() + im
+    }
+    #[inline]
+    fn sub_complex(self, im: Self::Complex) -> Self::Complex {
+        self.as_c() - im
+    }
+    #[inline]
+    fn mul_complex(self, im: Self::Complex) -> Self::Complex {
+        im * self
+    }
+    #[inline]
+    fn div_complex(self, im: Self::Complex) -> Self::Complex {
+        div::smith(self.as_c(), im)
+    }
+
+    impl_real!(
+        sqrt abs exp ln sin cos tan sinh cosh tanh asin acos atan asinh acosh atanh to_degrees
+        to_radians
+    );
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        Float::sin_cos(self)
+    }
+    #[inline]
+    fn rsqrt(self) -> Self {
+        Float::recip(Float::sqrt(self))
+    }
+    #[inline]
+    fn checked_sqrt(self) -> Option<Self> {
+        if self >= Self::ZERO {
+            Some(Float::sqrt(self))
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn expi(self) -> Self::Complex {
+        Self::cis(self)
+    }
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        Float::log(self, base)
+    }
+    fn erf(self) -> Self {
+        on_real_axis(special::erf, self)
+    }
+    fn erfc(self) -> Self {
+        on_real_axis(special::erfc, self)
+    }
+    fn gamma(self) -> Self {
+        let half = Self::real(0.5);
+        if self < half {
+            // reflection formula in real arithmetic, which does not underflow near zero
+            let pi = <Self as Scalar>::PI;
+            pi / (Float::sin(pi * self) * Scalar::gamma(Self::ONE - self))
+        } else {
+            let gamma = on_real_axis(special::gamma, self);
+            // the Lanczos approximation is `inf * 0` where it overflows
+            if Float::is_nan(gamma) {
+                Self::infinity()
+            } else {
+                gamma
+            }
+        }
+    }
+    fn ln_gamma(self) -> Self {
+        // the real part of the principal log-gamma is ln|Γ(x)| also on the negative axis
+        on_real_axis(special::ln_gamma, self)
+    }
+    fn logaddexp(self, other: Self) -> Self {
+        if Float::is_nan(self) || Float::is_nan(other) {
+            return self + other;
+        }
+        let (hi, lo) = if self >= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // +inf, or both -inf
+        if Float::is_infinite(hi) {
+            return hi;
+        }
+        hi + Float::ln_1p(Float::exp(lo - hi))
+    }
+    fn beta(a: Self, b: Self) -> Self {
+        if a > Self::ZERO && b > Self::ZERO {
+            Float::exp(Self::ln_beta(a, b))
+        } else {
+            Scalar::gamma(a) * Scalar::gamma(b) / Scalar::gamma(a + b)
+        }
+    }
+}
+
+impl<T: AdapterFloat> Scalar for Complex<FloatAdapter<T>> {
+    type Real = FloatAdapter<T>;
+    type Complex = Self;
+    type Widened = Self;
+
+    const IS_COMPLEX: bool = true;
+    const COMPONENTS: usize = 2;
+    const I: Self = Complex::new(FloatAdapter::ZERO, FloatAdapter::ONE);
+    const PI: Self::Real = FloatAdapter(<T as AdapterFloat>::PI);
+    const E: Self::Real = FloatAdapter(<T as AdapterFloat>::E);
+    const EPSILON: Self::Real = FloatAdapter(<T as AdapterFloat>::EPSILON);
+
+    #[inline]
+    fn widen(self) -> Self {
+        self
+    }
+    #[inline]
+    fn narrow(w: Self) -> Self {
+        w
+    }
+    fn frexp(self) -> (Self, i32) {
+        let larger = Float::max(Float::abs(self.re), Float::abs(self.im));
+        let (_, exp) = Scalar::frexp(larger);
+        (self.ldexp(-exp), exp)
+    }
+    #[inline]
+    fn ldexp(self, exp: i32) -> Self {
+        Self::new(Scalar::ldexp(self.re, exp), Scalar::ldexp(self.im, exp))
+    }
+
+    #[inline]
+    fn re(&self) -> Self::Real {
+        self.re
+    }
+    #[inline]
+    fn im(&self) -> Self::Real {
+        self.im
+    }
+    #[inline]
+    fn from_real(re: Self::Real) -> Self {
+        Self::new(re, FloatAdapter::ZERO)
+    }
+
+    #[inline]
+    fn lerp(self, other: Self, t: Self::Real) -> Self {
+        Self::new(
+            Scalar::lerp(self.re, other.re, t),
+            Scalar::lerp(self.im, other.im, t),
+        )
+    }
+    #[inline]
+    fn midpoint(self, other: Self) -> Self {
+        Self::new(
+            Scalar::midpoint(self.re, other.re),
+            Scalar::midpoint(self.im, other.im),
+        )
+    }
+
+    #[inline]
+    fn fmod(self, rhs: Self::Real) -> Self {
+        Self::new(self.re % rhs, self.im % rhs)
+    }
+    #[inline]
+    fn rem_euclid(self, rhs: Self::Real) -> Self {
+        Self::new(
+            Scalar::rem_euclid(self.re, rhs),
+            Scalar::rem_euclid(self.im, rhs),
+        )
+    }
+    #[inline]
+    fn modf(self) -> (Self, Self) {
+        let (re_int, re_fract) = Scalar::modf(self.re);
+        let (im_int, im_fract) = Scalar::modf(self.im);
+        (Self::new(re_int, im_int), Self::new(re_fract, im_fract))
+    }
+
+    fn pow(self, n: Self) -> Self {
+        self.powc(n)
+    }
+    fn powi(self, n: i32) -> Self {
+        // binary exponentiation, on the reciprocal for negative exponents
+        let mut base = if n < 0 {
+            div::smith(Self::ONE, self)
+        } else {
+            self
+        };
+        let mut e = n.unsigned_abs();
+        let mut acc = Self::ONE;
+        while e > 0 {
+            if e & 1 == 1 {
+                acc *= base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base *= base;
+            }
+        }
+        acc
+    }
+    fn powf(self, n: Self::Real) -> Self {
+        Complex::powf(self, n)
+    }
+    fn powc(self, n: Self) -> Self {
+        Complex::powc(self, n)
+    }
+    fn powf_c(self, n: Self::Real) -> Self {
+        Complex::powf(self, n)
+    }
+
+    #[inline]
+    fn real<P: ToPrimitive>(re: P) -> Self::Real {
+        NumCast::from(re).unwrap()
+    }
+    #[inline]
+    fn complex<P: ToPrimitive>(re: P, im: P) -> Self {
+        Complex::new(NumCast::from(re).unwrap(), NumCast::from(im).unwrap())
+    }
+    #[inline]
+    fn try_real<P: ToPrimitive>(re: P) -> Option<Self::Real> {
+        NumCast::from(re)
+    }
+    fn parse(s: &str) -> Result<Self, ParseScalarError> {
+        let (re, im) = parse::parse_parts::<T>(s)?;
+        Ok(Self::new(FloatAdapter(re), FloatAdapter(im)))
+    }
+    #[cfg(feature = "std")]
+    fn to_hex_string(&self) -> String {
+        let im = hex::format(self.im.0);
+        let sign = if im.starts_with('-') { "" } else { "+" };
+        format!("{}{}{}i", hex::format(self.re.0), sign, im)
+    }
+    #[cfg(feature = "std")]
+    fn to_shortest_string(&self) -> String {
+        let im = parse::shortest(self.im.0);
+        let sign = if im.starts_with('-') { "" } else { "+" };
+        format!("{}{}{}i", parse::shortest(self.re.0), sign, im)
+    }
+    fn from_hex_str(s: &str) -> Result<Self, ParseScalarError> {
+        let (re, im) = hex::parse_parts(s)?;
+        Ok(Self::new(re, im))
+    }
+    #[inline]
+    fn try_from_complex(c: Self) -> Option<Self> {
+        Some(c)
+    }
+    #[inline]
+    fn try_complex<P: ToPrimitive>(re: P, im: P) -> Option<Self> {
+        Some(Complex::new(NumCast::from(re)?, NumCast::from(im)?))
+    }
+    #[inline]
+    fn cis(theta: Self::Real) -> Self {
+        Self::Real::cis(theta)
+    }
+    #[inline]
+    fn i() -> Self {
+        Self::I
+    }
+    #[inline]
+    fn from_parts((re, im): (Self::Real, Self::Real)) -> Self {
+        Complex::new(re, im)
+    }
+    #[inline]
+    fn as_c(&self) -> Self {
+        *self
+    }
+    #[inline]
+    fn mul_i(self) -> Self {
+        Complex::new(-self.im, self.re)
+    }
+    #[inline]
+    fn div_i(self) -> Self {
+        Complex::new(self.im, -self.re)
+    }
+    #[inline]
+    fn conj(&self) -> Self {
+        Complex::conj(self)
+    }
+    #[inline]
+    fn proj(self) -> Self {
+        if Float::is_infinite(self.re) || Float::is_infinite(self.im) {
+            let zero = Float::copysign(FloatAdapter::ZERO, self.im);
+            Self::new(Self::Real::infinity(), zero)
+        } else {
+            self
+        }
+    }
+    #[inline]
+    fn conj_mul(self, other: Self) -> Self {
+        // (a - ib)(c + id) = (ac + bd) + i(ad - bc)
+        Self::new(
+            self.re * other.re + self.im * other.im,
+            self.re * other.im - self.im * other.re,
+        )
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        let re = Float::mul_add(self.re, a.re, Float::mul_add(-self.im, a.im, b.re));
+        let im = Float::mul_add(self.re, a.im, Float::mul_add(self.im, a.re, b.im));
+        Self::new(re, im)
+    }
+    #[inline]
+    fn square(self) -> Self::Real {
+        Complex::norm_sqr(&self)
+    }
+    #[inline]
+    fn abs(self) -> Self::Real {
+        // hypot scales internally, unlike sqrt(norm_sqr)
+        Float::hypot(self.re, self.im)
+    }
+    #[inline]
+    fn is_normal(&self) -> bool {
+        Float::is_normal(self.re) || Float::is_normal(self.im)
+    }
+    #[inline]
+    fn is_subnormal(&self) -> bool {
+        Scalar::is_subnormal(&self.re) || Scalar::is_subnormal(&self.im)
+    }
+
+    fn rand_normal(rng: &mut impl Rng) -> Self {
+        Self::new(sample(rng, StandardNormal), sample(rng, StandardNormal))
+    }
+    fn fill_rand(slice: &mut [Self], rng: &mut impl Rng) {
+        for z in slice {
+            *z = Self::rand(rng);
+        }
+    }
+    fn qrand(seq: &mut Halton) -> Self {
+        let (re, im) = seq.next_point();
+        Self::new(re, im)
+    }
+    fn rand_seeded(seed: u64, index: u64) -> Self {
+        let bits = distributions::splitmix64(seed, index.wrapping_mul(2).wrapping_add(1));
+        Self::new(Self::Real::rand_seeded(seed, index), unit(bits))
+    }
+    fn rand_cnormal(rng: &mut impl Rng) -> Self {
+        Self::rand_normal(rng).mul_real(Self::Real::FRAC_1_SQRT_2())
+    }
+    fn rand_exp(rng: &mut impl Rng, lambda: Self::Real) -> Self {
+        let r = Self::Real::rand_exp(rng, lambda);
+        Self::rand_unit(rng).mul_real(r)
+    }
+    fn rand_gamma(rng: &mut impl Rng, shape: Self::Real, scale: Self::Real) -> Self {
+        let r = Self::Real::rand_gamma(rng, shape, scale);
+        Self::rand_unit(rng).mul_real(r)
+    }
+    fn rand_unit(rng: &mut impl Rng) -> Self {
+        Self::cis(Self::Real::rand(rng) * Self::Real::TAU())
+    }
+    fn rand_disk(rng: &mut impl Rng) -> Self {
+        // rejection from the enclosing square accepts with probability pi/4
+        loop {
+            let z = Self::new(Self::Real::rand_disk(rng), Self::Real::rand_disk(rng));
+            if z.norm_sqr() <= FloatAdapter::ONE {
+                return z;
+            }
+        }
+    }
+    fn rand_range(rng: &mut impl Rng, lo: Self::Real, hi: Self::Real) -> Self {
+        assert!(
+            FloatAdapter::ZERO <= lo && lo < hi,
+            "invalid annulus {} <= |z| < {}",
+            lo,
+            hi
+        );
+        // the area below radius r grows as r^2
+        let (lo2, hi2) = (lo * lo, hi * hi);
+        let r = Float::sqrt(lo2 + Self::Real::rand(rng) * (hi2 - lo2));
+        let theta = Self::Real::rand(rng) * Self::Real::TAU();
+        Self::from_polar(r, theta)
+    }
+
+    #[inline]
+    fn add_real(self, re: Self::Real) -> Self {
+        self + re
+    }
+    #[inline]
+    fn sub_real(self, re: Self::Real) -> Self {
+        self - re
+    }
+    #[inline]
+    fn mul_real(self, re: Self::Real) -> Self {
+        self * re
+    }
+    #[inline]
+    fn div_real(self, re: Self::Real) -> Self {
+        self / re
+    }
+    #[inline]
+    fn add_complex(self, im: Self) -> Self {
+        self + im
+    }
+    #[inline]
+    fn sub_complex(self, im: Self) -> Self {
+        self - im
+    }
+    #[inline]
+    fn mul_complex(self, im: Self) -> Self {
+        self * im
+    }
+    #[inline]
+    fn div_complex(self, im: Self) -> Self {
+        div::smith(self, im)
+    }
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let q = div::smith(self, rhs);
+        if Float::is_nan(q.re) || Float::is_nan(q.im) {
+            None
+        } else {
+            Some(q)
+        }
+    }
+
+    impl_complex!(sqrt exp ln sin cos tan sinh cosh tanh asin acos atan asinh acosh atanh);
+
+    #[inline]
+    fn checked_sqrt(self) -> Option<Self> {
+        Some(Complex::sqrt(self))
+    }
+    fn rsqrt(self) -> Self {
+        if self.is_zero() {
+            return Self::new(Self::Real::infinity(), FloatAdapter::ZERO);
+        }
+        // 1 / s = conj(s) / |s|^2, scaled in two steps to avoid overflow
+        let s = Complex::sqrt(self);
+        let r = s.norm();
+        s.conj().unscale(r).unscale(r)
+    }
+    #[inline]
+    fn to_degrees(self) -> Self {
+        Self::new(Float::to_degrees(self.re), Float::to_degrees(self.im))
+    }
+    #[inline]
+    fn to_radians(self) -> Self {
+        Self::new(Float::to_radians(self.re), Float::to_radians(self.im))
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        // sin(a + ib) = sin(a) cosh(b) + i cos(a) sinh(b)
+        // cos(a + ib) = cos(a) cosh(b) - i sin(a) sinh(b)
+        let (s, c) = Float::sin_cos(self.re);
+        let (sh, ch) = (Float::sinh(self.im), Float::cosh(self.im));
+        (Self::new(s * ch, c * sh), Self::new(c * ch, -s * sh))
+    }
+    #[inline]
+    fn expi(self) -> Self {
+        // exp(i (a + ib)) = exp(-b) (cos(a) + i sin(a))
+        Self::cis(self.re) * Float::exp(-self.im)
+    }
+    #[inline]
+    fn log(self, base: Self::Real) -> Self {
+        Complex::log(self, base)
+    }
+    fn erf(self) -> Self {
+        special::erf(self)
+    }
+    fn erfc(self) -> Self {
+        special::erfc(self)
+    }
+    fn gamma(self) -> Self {
+        special::gamma(self)
+    }
+    fn ln_gamma(self) -> Self {
+        special::ln_gamma(self)
+    }
+    #[inline]
+    fn beta(a: Self, b: Self) -> Self {
+        Self::ln_beta(a, b).exp()
+    }
+    fn logaddexp(self, other: Self) -> Self {
+        let (hi, lo) = if self.re >= other.re {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        if Float::is_infinite(hi.re) {
+            return hi;
+        }
+        hi + special::ln_1p((lo - hi).exp())
+    }
+}
@@ -0,0 +1,104 @@
+//! Scalars decoded from the unstructured bytes of fuzzers with
+//! [arbitrary](https://docs.rs/arbitrary)
+//!
+//! The orphan rule forbids implementing `Arbitrary` for `c32` and `c64`, so that [Any] and
+//! [Finite] wrap a scalar of any type `A: ScalarLayout` instead. The functions of this module
+//! generate constrained scalars inside of other `Arbitrary` implementations.
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use cauchy::{arbitrary::{bounded, Finite}, c32, Scalar};
+//!
+//! let mut u = Unstructured::new(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x42, 0x24]);
+//! let Finite(z) = Finite::<c32>::arbitrary(&mut u).unwrap();
+//! assert!(z.re.is_finite() && z.im.is_finite());
+//! let x: f64 = bounded(&mut u, 2.0).unwrap();
+//! assert!(x.abs() <= 2.0);
+//! ```
+
+use crate::{
+    generate::{unit_from, well_conditioned_from},
+    sum::from_components,
+    RealScalar, Scalar, ScalarLayout,
+};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use num_traits::{Float, Zero};
+
+/// Any scalar, including infinities, NaN and subnormal numbers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Any<A>(pub A);
+
+/// Finite scalar with components of any magnitude
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Finite<A>(pub A);
+
+impl<'a, A: ScalarLayout> Arbitrary<'a> for Any<A> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        any(u).map(Any)
+    }
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        let size = core::mem::size_of::<A::Bytes>();
+        (size, Some(size))
+    }
+}
+
+impl<'a, A: ScalarLayout> Arbitrary<'a> for Finite<A> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        finite(u).map(Finite)
+    }
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        let size = core::mem::size_of::<A::Bytes>();
+        (size, Some(size))
+    }
+}
+
+/// Any scalar decoded from its little-endian bytes, filling missing bytes with zeros
+pub fn any<A: ScalarLayout>(u: &mut Unstructured) -> Result<A> {
+    let mut bytes = A::Bytes::default();
+    u.fill_buffer(bytes.as_mut())?;
+    Ok(A::from_le_bytes(bytes))
+}
+
+/// Replace infinities by the largest finite number of the same sign and NaN by zero
+fn clamp_finite<R: RealScalar>(x: R) -> R {
+    if Float::is_nan(x) {
+        R::zero()
+    } else {
+        Float::max(Float::min(x, R::max_value()), R::min_value())
+    }
+}
+
+/// Finite scalar, with the infinite and NaN components of [any] replaced by finite ones
+pub fn finite<A: ScalarLayout>(u: &mut Unstructured) -> Result<A> {
+    let x: A = any(u)?;
+    Ok(from_components(clamp_finite(x.re()), clamp_finite(x.im())))
+}
+
+/// Uniform real number in `[-max, max]`
+fn bounded_real<R: RealScalar>(u: &mut Unstructured, max: f64) -> Result<R> {
+    let t = u.int_in_range(0..=u32::MAX)? as f64 / u32::MAX as f64;
+    Ok(R::real(max * (2.0 * t - 1.0)))
+}
+
+/// Decoded [bounded](crate::proptest::bounded) scalar
+pub fn bounded<A: Scalar>(u: &mut Unstructured, max: f64) -> Result<A> {
+    let re = bounded_real(u, max)?;
+    let im = if A::IS_COMPLEX {
+        bounded_real(u, max)?
+    } else {
+        A::Real::zero()
+    };
+    Ok(from_components(re, im))
+}
+
+/// Decoded [unit](crate::proptest::unit) scalar
+pub fn unit<A: Scalar>(u: &mut Unstructured) -> Result<A> {
+    let neg = bool::arbitrary(u)?;
+    Ok(unit_from(neg, bounded_real(u, 1.0)?))
+}
+
+/// Decoded [well-conditioned](crate::proptest::well_conditioned) scalar
+pub fn well_conditioned<A: Scalar>(u: &mut Unstructured) -> Result<A> {
+    let z: A = unit(u)?;
+    Ok(well_conditioned_from(z, bounded_real(u, 4.0)?))
+}
@@ -0,0 +1,38 @@
+//! Architecture specific fast paths
+
+use num_traits::Float;
+
+/// Reciprocal square root `1 / sqrt(x)`
+pub(crate) trait Rsqrt {
+    fn rsqrt(self) -> Self;
+}
+
+impl Rsqrt for f32 {
+    #[inline]
+    fn rsqrt(self) -> Self {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse"
+        ))]
+        {
+            if self.is_normal() && self > 0.0 {
+                #[cfg(target_arch = "x86")]
+                use core::arch::x86::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+                #[cfg(target_arch = "x86_64")]
+                use core::arch::x86_64::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+                // SAFETY: SSE is enabled at compile time
+                let y = unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(self))) };
+                // one Newton step refines the 12-bit estimate to nearly full precision
+                return y * (1.5 - 0.5 * self * y * y);
+            }
+        }
+        1.0 / Float::sqrt(self)
+    }
+}
+
+impl Rsqrt for f64 {
+    #[inline]
+    fn rsqrt(self) -> Self {
+        1.0 / Float::sqrt(self)
+    }
+}
@@ -0,0 +1,134 @@
+//! Conversions to and from arrays of [Apache Arrow](https://docs.rs/arrow)
+//!
+//! Real numbers are `Float32` and `Float64` arrays, and complex numbers are
+//! `FixedSizeList` arrays of two non-nullable components `[re, im]`, which is the usual
+//! encoding of complex numbers in Arrow and Parquet files, e.g. of pandas and polars.
+//! Arrays with null values cannot be converted.
+//!
+//! ```
+//! use arrow_array::Array;
+//! use arrow_schema::DataType;
+//! use cauchy::{arrow::ArrowScalar, c32};
+//!
+//! let zs = [c32::new(1.0, 2.0), c32::new(-3.0, 0.5)];
+//! let array = c32::to_arrow(&zs);
+//! assert_eq!(array.len(), 2);
+//! assert!(matches!(array.data_type(), DataType::FixedSizeList(_, 2)));
+//! assert_eq!(c32::from_arrow(&*array).unwrap(), zs);
+//!
+//! // no implicit casts between precisions
+//! assert!(f64::from_arrow(&*f32::to_arrow(&[1.0, 2.0])).is_err());
+//! ```
+
+use crate::{RealScalar, Scalar};
+use ::arrow_array::{
+    cast::AsArray,
+    types::{ArrowPrimitiveType, Float32Type, Float64Type},
+    Array, ArrayRef, ArrowNativeTypeOp, FixedSizeListArray, PrimitiveArray,
+};
+use ::arrow_schema::{ArrowError, DataType, Field};
+use std::sync::Arc;
+
+/// Real type with a primitive Arrow type
+pub trait ArrowReal: RealScalar + ArrowNativeTypeOp {
+    /// Primitive type of Arrow arrays of `Self`
+    type Primitive: ArrowPrimitiveType<Native = Self>;
+}
+
+impl ArrowReal for f32 {
+    type Primitive = Float32Type;
+}
+
+impl ArrowReal for f64 {
+    type Primitive = Float64Type;
+}
+
+/// [Scalar] which converts to and from Arrow arrays,
+/// implemented for every scalar whose real type is `f32` or `f64`
+pub trait ArrowScalar: Scalar {
+    /// Arrow type of arrays of `Self`
+    fn data_type() -> DataType;
+    /// Copy into an array of [ArrowScalar::data_type] without nulls
+    fn to_arrow(xs: &[Self]) -> ArrayRef;
+    /// Copy from an array of [ArrowScalar::data_type] without nulls, where the components of
+    /// complex numbers may have any name and nullability
+    fn from_arrow(array: &dyn Array) -> Result<Vec<Self>, ArrowError>;
+}
+
+/// Error for arrays which cannot be converted into `A`
+fn incompatible<A: ArrowScalar>(array: &dyn Array) -> ArrowError {
+    ArrowError::CastError(format!(
+        "cannot convert Arrow array of {} into {}",
+        array.data_type(),
+        A::data_type()
+    ))
+}
+
+/// Error for arrays with nulls
+fn check_nulls(array: &dyn Array) -> Result<(), ArrowError> {
+    match array.null_count() {
+        0 => Ok(()),
+        n => Err(ArrowError::InvalidArgumentError(format!(
+            "cannot convert Arrow array with {} nulls into scalars",
+            n
+        ))),
+    }
+}
+
+/// Values of an array without nulls
+fn components<A: ArrowScalar>(array: &dyn Array) -> Result<&[A::Real], ArrowError>
+where
+    A::Real: ArrowReal,
+{
+    check_nulls(array)?;
+    let values = array
+        .as_primitive_opt::<<A::Real as ArrowReal>::Primitive>()
+        .ok_or_else(|| incompatible::<A>(array))?;
+    Ok(&values.values()[..])
+}
+
+impl<A: Scalar> ArrowScalar for A
+where
+    A::Real: ArrowReal,
+{
+    fn data_type() -> DataType {
+        let real = <<A::Real as ArrowReal>::Primitive as ArrowPrimitiveType>::DATA_TYPE;
+        if A::IS_COMPLEX {
+            DataType::FixedSizeList(Arc::new(Field::new_list_field(real, false)), 2)
+        } else {
+            real
+        }
+    }
+
+    fn to_arrow(xs: &[Self]) -> ArrayRef {
+        let values = PrimitiveArray::<<A::Real as ArrowReal>::Primitive>::from_iter_values(
+            xs.iter()
+                .flat_map(|x| IntoIterator::into_iter([x.re(), x.im()]).take(A::COMPONENTS)),
+        );
+        match A::data_type() {
+            DataType::FixedSizeList(field, size) => {
+                Arc::new(FixedSizeListArray::new(field, size, Arc::new(values), None))
+            }
+            _ => Arc::new(values),
+        }
+    }
+
+    fn from_arrow(array: &dyn Array) -> Result<Vec<Self>, ArrowError> {
+        if !A::IS_COMPLEX {
+            return Ok(components::<A>(array)?
+                .iter()
+                .map(|&re| A::from_real(re))
+                .collect());
+        }
+        let list = array
+            .as_fixed_size_list_opt()
+            .filter(|list| list.value_length() == 2)
+            .ok_or_else(|| incompatible::<A>(array))?;
+        check_nulls(list)?;
+        let parts = components::<A>(list.values().as_ref())?;
+        Ok(parts
+            .chunks_exact(2)
+            .map(|c| crate::sum::from_components(c[0], c[1]))
+            .collect())
+    }
+}
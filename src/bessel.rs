@@ -0,0 +1,139 @@
+//! Cylindrical Bessel functions
+
+use crate::Scalar;
+use num_traits::{Float, FloatConst};
+
+/// Cylindrical Bessel functions of integer order for real arguments
+///
+/// ```
+/// use cauchy::Bessel;
+///
+/// assert!((1.0_f64.bessel_j0() - 0.7651976865579666).abs() < 1e-15);
+/// assert!((1.0_f64.bessel_k0() - 0.42102443824070834).abs() < 1e-15);
+/// ```
+pub trait Bessel: Scalar {
+    /// Bessel function of the first kind of order zero, `J0(x)`
+    fn bessel_j0(self) -> Self;
+    /// Bessel function of the first kind of order one, `J1(x)`
+    fn bessel_j1(self) -> Self;
+    /// Bessel function of the second kind of order zero, `Y0(x)`
+    fn bessel_y0(self) -> Self;
+    /// Modified Bessel function of the first kind of order zero, `I0(x)`
+    fn bessel_i0(self) -> Self;
+    /// Modified Bessel function of the second kind of order zero, `K0(x)`
+    fn bessel_k0(self) -> Self;
+}
+
+fn cast<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Asymptotic expansion `Σ_k (±1)^k ((2k-1)!!)^2 / (k! (8x)^k)` shared by `I0` and `K0`
+fn asymptotic<T: Float>(x: T, sign: T) -> T {
+    let mut term = T::one();
+    let mut sum = T::one();
+    for k in 1..100 {
+        let odd: T = cast((2 * k - 1) as f64);
+        let next = term * sign * odd * odd / (cast::<T>((8 * k) as f64) * x);
+        if next.abs() >= term.abs() {
+            break;
+        }
+        term = next;
+        sum = sum + term;
+        if term.abs() <= T::epsilon() * sum {
+            break;
+        }
+    }
+    sum
+}
+
+fn i0<T: Float + FloatConst>(x: T) -> T {
+    let x = x.abs();
+    if x <= cast(25.0) {
+        // I0(x) = Σ_k (x^2 / 4)^k / (k!)^2
+        let q = x * x / cast(4.0);
+        let mut term = T::one();
+        let mut sum = T::one();
+        for k in 1..500 {
+            let k: T = cast(k as f64);
+            term = term * q / (k * k);
+            sum = sum + term;
+            if term <= T::epsilon() * sum {
+                break;
+            }
+        }
+        sum
+    } else {
+        x.exp() / (x * (T::PI() + T::PI())).sqrt() * asymptotic(x, T::one())
+    }
+}
+
+fn k0<T: Float + FloatConst>(x: T) -> T {
+    if x < T::zero() {
+        return T::nan();
+    }
+    if x == T::zero() {
+        return T::infinity();
+    }
+    if x <= T::one() {
+        // K0(x) = -(ln(x/2) + γ) I0(x) + Σ_k (x^2 / 4)^k / (k!)^2 H_k
+        let euler: T = cast(0.577_215_664_901_532_9);
+        let q = x * x / cast(4.0);
+        let mut term = T::one();
+        let mut harmonic = T::zero();
+        let mut sum = T::zero();
+        for k in 1..100 {
+            let k: T = cast(k as f64);
+            term = term * q / (k * k);
+            harmonic = harmonic + k.recip();
+            sum = sum + term * harmonic;
+            if term * harmonic <= T::epsilon() * sum {
+                break;
+            }
+        }
+        sum - ((x / cast(2.0)).ln() + euler) * i0(x)
+    } else if x <= cast(25.0) {
+        // trapezoidal rule for K0(x) = ∫_0^∞ exp(-x cosh t) dt, which converges geometrically
+        let h: T = cast(0.125);
+        let mut sum = cast::<T>(0.5);
+        for k in 1..1000 {
+            let t = h * cast(k as f64);
+            let term = (-x * (t.cosh() - T::one())).exp();
+            sum = sum + term;
+            if term <= T::epsilon() * sum {
+                break;
+            }
+        }
+        sum * h * (-x).exp()
+    } else {
+        (T::FRAC_PI_2() / x).sqrt() * (-x).exp() * asymptotic(x, -T::one())
+    }
+}
+
+macro_rules! impl_bessel {
+    ($real:ty) => {
+        impl Bessel for $real {
+            #[inline]
+            fn bessel_j0(self) -> Self {
+                libm::Libm::<$real>::j0(self)
+            }
+            #[inline]
+            fn bessel_j1(self) -> Self {
+                libm::Libm::<$real>::j1(self)
+            }
+            #[inline]
+            fn bessel_y0(self) -> Self {
+                libm::Libm::<$real>::y0(self)
+            }
+            fn bessel_i0(self) -> Self {
+                i0(self as f64) as $real
+            }
+            fn bessel_k0(self) -> Self {
+                k0(self as f64) as $real
+            }
+        }
+    };
+}
+
+impl_bessel!(f32);
+impl_bessel!(f64);
@@ -0,0 +1,204 @@
+//! BLAS level-1 routines over slices of scalars
+
+use crate::{
+    sum::{from_components, two_prod, two_sum, Neumaier},
+    Scalar,
+};
+use num_traits::{Float, One, Zero};
+
+/// Sum of products `x_i y_i`, with `x_i` conjugated if `conj`, compensated component-wise
+fn dot<A: Scalar>(x: &[A], y: &[A], conj: bool) -> A {
+    assert_eq!(
+        x.len(),
+        y.len(),
+        "dot product of slices of different lengths"
+    );
+    let mut re = Neumaier::new();
+    let mut im = Neumaier::new();
+    for (a, b) in x.iter().zip(y) {
+        let (ar, ai) = (a.re(), if conj { -a.im() } else { a.im() });
+        let (br, bi) = (b.re(), b.im());
+        re.add(ar * br);
+        re.add(-(ai * bi));
+        im.add(ar * bi);
+        im.add(ai * br);
+    }
+    from_components(re.value(), im.value())
+}
+
+/// Dot product `sum_i conj(x_i) y_i` conjugating `x`, as `?dotc` of BLAS
+///
+/// The products are accumulated with compensated summation. Panics if the lengths differ.
+///
+/// ```
+/// use cauchy::{blas::{dotc, dotu}, c64};
+///
+/// let x = [c64::new(0.0, 1.0)];
+/// assert_eq!(dotc(&x, &x), c64::new(1.0, 0.0));
+/// assert_eq!(dotu(&x, &x), c64::new(-1.0, 0.0));
+/// ```
+pub fn dotc<A: Scalar>(x: &[A], y: &[A]) -> A {
+    dot(x, y, true)
+}
+
+/// Dot product `sum_i x_i y_i` without conjugation, as `?dotu` of BLAS
+///
+/// The products are accumulated with compensated summation. Panics if the lengths differ.
+pub fn dotu<A: Scalar>(x: &[A], y: &[A]) -> A {
+    dot(x, y, false)
+}
+
+/// Sum of products in about twice the working precision, as Dot2 of Ogita, Rump and Oishi
+#[derive(Debug, Clone, Copy)]
+struct Dot2<T> {
+    sum: T,
+    err: T,
+}
+
+impl<T: Float> Dot2<T> {
+    fn new() -> Self {
+        Dot2 {
+            sum: T::zero(),
+            err: T::zero(),
+        }
+    }
+
+    fn add_product(&mut self, a: T, b: T) {
+        let (p, q) = two_prod(a, b);
+        let (s, r) = two_sum(self.sum, p);
+        self.sum = s;
+        self.err = self.err + (q + r);
+    }
+
+    fn value(&self) -> T {
+        self.sum + self.err
+    }
+}
+
+/// Dot product `sum_i x_i y_i` without conjugation as [dotu],
+/// computed as if in twice the working precision and then rounded
+///
+/// The error-free transformations of Ogita, Rump and Oishi make the result as accurate as
+/// [dotu] evaluated in doubled precision, e.g. for residuals in iterative refinement.
+/// Panics if the lengths differ.
+///
+/// ```
+/// use cauchy::blas::dot_compensated;
+///
+/// let x = [1e8, 1.0, -1e8];
+/// let y = [1e8, 1.0, 1e8];
+/// assert_eq!(dot_compensated(&x, &y), 1.0);
+/// assert_eq!(x.iter().zip(&y).map(|(a, b)| a * b).sum::<f64>(), 0.0);
+/// ```
+pub fn dot_compensated<A: Scalar>(x: &[A], y: &[A]) -> A {
+    assert_eq!(
+        x.len(),
+        y.len(),
+        "dot product of slices of different lengths"
+    );
+    let mut re = Dot2::new();
+    let mut im = Dot2::new();
+    for (a, b) in x.iter().zip(y) {
+        re.add_product(a.re(), b.re());
+        re.add_product(-a.im(), b.im());
+        im.add_product(a.re(), b.im());
+        im.add_product(a.im(), b.re());
+    }
+    from_components(re.value(), im.value())
+}
+
+/// Euclidean norm `sqrt(sum_i |x_i|^2)`, as `?nrm2` of BLAS
+///
+/// The squares are scaled by the largest magnitude seen so far as in the reference BLAS,
+/// so that the result neither overflows nor underflows unless the norm itself does.
+///
+/// ```
+/// use cauchy::blas::norm2;
+///
+/// assert_eq!(norm2(&[3e30_f32, 4e30]), 5e30);
+/// assert_eq!(norm2(&[3e-30_f32, 4e-30]), 5e-30);
+/// ```
+pub fn norm2<A: Scalar>(x: &[A]) -> A::Real {
+    let zero = A::Real::zero();
+    let mut scale = zero;
+    let mut ssq = A::Real::one();
+    let mut infinite = false;
+    for v in x.iter().flat_map(|v| [v.re(), v.im()]) {
+        let a = Float::abs(v);
+        if a.is_nan() {
+            return a;
+        }
+        if a.is_infinite() {
+            infinite = true;
+        } else if a > zero {
+            if scale < a {
+                ssq = A::Real::one() + ssq * Scalar::square(scale / a);
+                scale = a;
+            } else {
+                ssq += Scalar::square(a / scale);
+            }
+        }
+    }
+    if infinite {
+        A::Real::infinity()
+    } else {
+        scale * Float::sqrt(ssq)
+    }
+}
+
+/// Scale in place `x_i <- alpha x_i`, as `?scal` of BLAS
+pub fn scal<A: Scalar>(alpha: A, x: &mut [A]) {
+    for v in x {
+        *v *= alpha;
+    }
+}
+
+/// Add a multiple in place `y_i <- alpha x_i + y_i`, as `?axpy` of BLAS
+///
+/// Panics if the lengths differ.
+///
+/// ```
+/// use cauchy::blas::{axpy, scal};
+///
+/// let mut y = [1.0, 2.0];
+/// axpy(2.0, &[1.0, 1.0], &mut y);
+/// assert_eq!(y, [3.0, 4.0]);
+/// scal(0.5, &mut y);
+/// assert_eq!(y, [1.5, 2.0]);
+/// ```
+pub fn axpy<A: Scalar>(alpha: A, x: &[A], y: &mut [A]) {
+    assert_eq!(x.len(), y.len(), "axpy of slices of different lengths");
+    for (v, &u) in y.iter_mut().zip(x) {
+        *v += alpha * u;
+    }
+}
+
+/// Index of the first element of largest magnitude `|re| + |im|`, as `i?amax` of BLAS,
+/// or `None` if `x` is empty
+///
+/// A NaN element counts as larger than any number, so that the index of the first NaN is
+/// returned if there is one.
+///
+/// ```
+/// use cauchy::{blas::iamax, c64};
+///
+/// assert_eq!(iamax(&[1.0, -3.0, 3.0]), Some(1));
+/// // |3 + 3i| is smaller than |-5|, but not in the BLAS convention
+/// assert_eq!(iamax(&[c64::new(-5.0, 0.0), c64::new(3.0, 3.0)]), Some(1));
+/// assert_eq!(iamax(&[1.0, f64::NAN, 2.0]), Some(1));
+/// assert_eq!(iamax::<f64>(&[]), None);
+/// ```
+pub fn iamax<A: Scalar>(x: &[A]) -> Option<usize> {
+    let mut best: Option<(usize, A::Real)> = None;
+    for (i, v) in x.iter().enumerate() {
+        let a = Float::abs(v.re()) + Float::abs(v.im());
+        if a.is_nan() {
+            return Some(i);
+        }
+        match best {
+            Some((_, b)) if a <= b => {}
+            _ => best = Some((i, a)),
+        }
+    }
+    best.map(|(i, _)| i)
+}
@@ -0,0 +1,92 @@
+//! Scalars in CUDA device memory, with `DeviceCopy` of [cust](https://docs.rs/cust)
+//!
+//! `c32` and `c64` have the size and the component order of `cuComplex` and
+//! `cuDoubleComplex` of `cuComplex.h`, which is asserted at compile time, so that buffers
+//! of them can be passed to kernels and to cuBLAS or cuFFT as they are. The CUDA types are
+//! more strictly aligned, to 8 and 16 bytes respectively. This does not matter for buffers
+//! allocated by CUDA, but `c64` fields of structs shared with device code need the alignment
+//! of [CuDoubleComplex], whose slices cast to those of the types of this crate.
+//!
+//! ```
+//! use cauchy::{c64, cuda::{CuDoubleComplex, DeviceScalar}};
+//!
+//! #[derive(Clone, Copy)]
+//! #[repr(C)]
+//! struct Params {
+//!     n: u32,
+//!     shift: CuDoubleComplex,
+//! }
+//!
+//! fn upload<A: DeviceScalar>(xs: &[A]) -> usize {
+//!     // e.g. cust::memory::DeviceBuffer::from_slice(xs)
+//!     core::mem::size_of_val(xs)
+//! }
+//!
+//! let params = Params { n: 2, shift: c64::new(0.5, -1.0).into() };
+//! let shifts = [params.shift; 2];
+//! assert_eq!(upload(CuDoubleComplex::as_complex_slice(&shifts)), 32);
+//! ```
+
+use crate::{c32, c64, Scalar};
+use core::mem::{align_of, offset_of, size_of};
+use cust_core::DeviceCopy;
+
+/// [Scalar] which can be copied to and from CUDA devices
+pub trait DeviceScalar: Scalar + DeviceCopy {}
+
+impl<A: Scalar + DeviceCopy> DeviceScalar for A {}
+
+macro_rules! impl_cu_complex {
+    ($cu:ident, $c_name:literal, $real:ty, $complex:ty, $align:literal) => {
+        /// Complex number with the layout of
+        #[doc = concat!("`", $c_name, "`")]
+        /// of `cuComplex.h`
+        #[derive(Debug, Clone, Copy, PartialEq, Default)]
+        #[repr(C, align($align))]
+        pub struct $cu {
+            pub x: $real,
+            pub y: $real,
+        }
+
+        // SAFETY: plain old data without references
+        unsafe impl DeviceCopy for $cu {}
+
+        const _: () = {
+            assert!(size_of::<$complex>() == size_of::<$cu>());
+            assert!(align_of::<$complex>() <= align_of::<$cu>());
+            assert!(offset_of!($complex, re) == offset_of!($cu, x));
+            assert!(offset_of!($complex, im) == offset_of!($cu, y));
+        };
+
+        impl From<$complex> for $cu {
+            fn from(z: $complex) -> Self {
+                $cu { x: z.re, y: z.im }
+            }
+        }
+
+        impl From<$cu> for $complex {
+            fn from(z: $cu) -> Self {
+                <$complex>::new(z.x, z.y)
+            }
+        }
+
+        impl $cu {
+            /// View as complex numbers of this crate
+            pub fn as_complex_slice(zs: &[Self]) -> &[$complex] {
+                // SAFETY: same size and component order, asserted above, and looser alignment
+                unsafe { core::slice::from_raw_parts(zs.as_ptr() as *const $complex, zs.len()) }
+            }
+
+            /// Mutable version of `as_complex_slice`
+            pub fn as_complex_slice_mut(zs: &mut [Self]) -> &mut [$complex] {
+                // SAFETY: same size and component order, asserted above, and looser alignment
+                unsafe {
+                    core::slice::from_raw_parts_mut(zs.as_mut_ptr() as *mut $complex, zs.len())
+                }
+            }
+        }
+    };
+}
+
+impl_cu_complex!(CuComplex, "cuComplex", f32, c32, 8);
+impl_cu_complex!(CuDoubleComplex, "cuDoubleComplex", f64, c64, 16);
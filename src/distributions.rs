@@ -0,0 +1,47 @@
+//! Adapters for sampling scalars from distributions over real numbers
+
+use num_complex::Complex;
+use rand::{distributions::Distribution, Rng};
+
+/// Sample the real and imaginary parts of complex numbers independently from a distribution
+/// over real numbers, or real numbers from it directly
+///
+/// ```
+/// use cauchy::*;
+/// use rand::prelude::*;
+/// use rand_distr::Normal;
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let noise = Componentwise(Normal::new(0.0, 0.1).unwrap());
+/// let z = c64::rand_using(&mut rng, &noise);
+/// let x = f64::rand_using(&mut rng, &noise);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Componentwise<D>(pub D);
+
+impl<T, D: Distribution<T>> Distribution<Complex<T>> for Componentwise<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex<T> {
+        Complex::new(self.0.sample(rng), self.0.sample(rng))
+    }
+}
+
+macro_rules! impl_componentwise {
+    ($real:ty) => {
+        impl<D: Distribution<$real>> Distribution<$real> for Componentwise<D> {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $real {
+                self.0.sample(rng)
+            }
+        }
+    };
+}
+
+impl_componentwise!(f32);
+impl_componentwise!(f64);
+
+/// Output of the splitmix64 generator seeded by `seed` at position `counter`
+pub(crate) fn splitmix64(seed: u64, counter: u64) -> u64 {
+    let mut z = seed.wrapping_add(counter.wrapping_add(1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,35 @@
+//! Complex division
+
+use num_complex::Complex;
+use num_traits::Float;
+
+/// Complex division `a / b` by Smith's algorithm
+///
+/// This scales by the larger component of `b` instead of forming `|b|^2`,
+/// which avoids spurious overflow and underflow for operands with large exponents.
+pub(crate) fn smith<T: Float>(a: Complex<T>, b: Complex<T>) -> Complex<T> {
+    if b.re.abs() >= b.im.abs() {
+        let r = b.im / b.re;
+        let den = b.re + b.im * r;
+        if r.is_zero() {
+            // r underflows; keep the order of operations for the tiny term
+            Complex::new(
+                (a.re + b.im * (a.im / b.re)) / den,
+                (a.im - b.im * (a.re / b.re)) / den,
+            )
+        } else {
+            Complex::new((a.re + a.im * r) / den, (a.im - a.re * r) / den)
+        }
+    } else {
+        let r = b.re / b.im;
+        let den = b.re * r + b.im;
+        if r.is_zero() {
+            Complex::new(
+                (b.re * (a.re / b.im) + a.im) / den,
+                (b.re * (a.im / b.im) - a.re) / den,
+            )
+        } else {
+            Complex::new((a.re * r + a.im) / den, (a.im * r - a.re) / den)
+        }
+    }
+}
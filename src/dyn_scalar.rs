@@ -0,0 +1,138 @@
+//! Object-safe scalar trait for choosing the precision at runtime
+//!
+//! ```
+//! use cauchy::{c32, dyn_scalar::{boxed, DynScalar}};
+//!
+//! let single = std::env::args().count() > 100;
+//! let x: Box<dyn DynScalar> = if single { boxed(2.0_f32) } else { boxed(2.0_f64) };
+//! let y = x.mul_dyn(&*x).unwrap();
+//! assert_eq!(y.to_c64().re, 4.0);
+//! assert_eq!(y.type_name(), "f64");
+//! assert_eq!(boxed(c32::new(1.0, 0.0)).type_name(), "c32");
+//! assert_eq!(y.downcast_ref::<f64>(), Some(&4.0));
+//!
+//! // operands of different types are rejected
+//! assert!(y.add_dyn(&c32::new(1.0, 0.0)).is_none());
+//! ```
+
+use crate::{c32, c64, Scalar, ScalarFmt};
+use core::any::{Any, TypeId};
+use core::fmt::Debug;
+
+/// Scalar of a type chosen at runtime, implemented for every [Scalar]
+///
+/// Binary operations return `None` unless both operands have the same concrete type.
+pub trait DynScalar: Debug + ScalarFmt + Send + Sync {
+    /// Name of the concrete type, e.g. `"c32"`
+    fn type_name(&self) -> &'static str;
+    /// Whether the concrete type is complex
+    fn is_complex(&self) -> bool;
+    /// Convert into `c64`, exactly for all supported types
+    fn to_c64(&self) -> c64;
+    /// Upcast for downcasting with [Any]
+    fn as_any(&self) -> &dyn Any;
+    /// Clone into a new box
+    fn clone_box(&self) -> Box<dyn DynScalar>;
+
+    /// Sum `self + other`
+    fn add_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>>;
+    /// Difference `self - other`
+    fn sub_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>>;
+    /// Product `self * other`
+    fn mul_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>>;
+    /// Quotient `self / other`
+    fn div_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>>;
+    /// Negation `-self`
+    fn neg_dyn(&self) -> Box<dyn DynScalar>;
+    /// Absolute value, in the real type of the same precision
+    fn abs_dyn(&self) -> Box<dyn DynScalar>;
+    /// Principal square root
+    fn sqrt_dyn(&self) -> Box<dyn DynScalar>;
+    /// Exponential function
+    fn exp_dyn(&self) -> Box<dyn DynScalar>;
+    /// Natural logarithm
+    fn ln_dyn(&self) -> Box<dyn DynScalar>;
+}
+
+/// Box a scalar for dynamic dispatch
+pub fn boxed<A: Scalar>(a: A) -> Box<dyn DynScalar> {
+    Box::new(a)
+}
+
+impl<'a> dyn DynScalar + 'a {
+    /// Reference to the value if its concrete type is `A`
+    pub fn downcast_ref<A: Scalar>(&self) -> Option<&A> {
+        self.as_any().downcast_ref()
+    }
+}
+
+impl Clone for Box<dyn DynScalar> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Short name of the scalar types of this crate, and the full path for other types
+///
+/// The types are told apart by their [TypeId], since the output of
+/// [core::any::type_name] is not guaranteed to stay the same.
+fn short_type_name<A: Scalar>() -> &'static str {
+    let id = TypeId::of::<A>();
+    if id == TypeId::of::<f32>() {
+        "f32"
+    } else if id == TypeId::of::<f64>() {
+        "f64"
+    } else if id == TypeId::of::<c32>() {
+        "c32"
+    } else if id == TypeId::of::<c64>() {
+        "c64"
+    } else {
+        core::any::type_name::<A>()
+    }
+}
+
+impl<A: Scalar> DynScalar for A {
+    fn type_name(&self) -> &'static str {
+        short_type_name::<A>()
+    }
+    fn is_complex(&self) -> bool {
+        A::IS_COMPLEX
+    }
+    fn to_c64(&self) -> c64 {
+        self.cast_lossy()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn clone_box(&self) -> Box<dyn DynScalar> {
+        boxed(*self)
+    }
+
+    fn add_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>> {
+        Some(boxed(*self + *other.downcast_ref::<A>()?))
+    }
+    fn sub_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>> {
+        Some(boxed(*self - *other.downcast_ref::<A>()?))
+    }
+    fn mul_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>> {
+        Some(boxed(*self * *other.downcast_ref::<A>()?))
+    }
+    fn div_dyn(&self, other: &dyn DynScalar) -> Option<Box<dyn DynScalar>> {
+        Some(boxed(*self / *other.downcast_ref::<A>()?))
+    }
+    fn neg_dyn(&self) -> Box<dyn DynScalar> {
+        boxed(-*self)
+    }
+    fn abs_dyn(&self) -> Box<dyn DynScalar> {
+        boxed(Scalar::abs(*self))
+    }
+    fn sqrt_dyn(&self) -> Box<dyn DynScalar> {
+        boxed(Scalar::sqrt(*self))
+    }
+    fn exp_dyn(&self) -> Box<dyn DynScalar> {
+        boxed(Scalar::exp(*self))
+    }
+    fn ln_dyn(&self) -> Box<dyn DynScalar> {
+        boxed(Scalar::ln(*self))
+    }
+}
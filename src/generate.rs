@@ -0,0 +1,20 @@
+//! Mappings of random values onto scalars, shared by the generators of [crate::proptest]
+//! and [crate::arbitrary]
+
+use crate::Scalar;
+use num_traits::Float;
+
+/// `±exp(iπt)` for `t` in `[-1, 1]`, and `±1` for real types
+pub(crate) fn unit_from<A: Scalar>(neg: bool, t: A::Real) -> A {
+    let sign = if neg { -A::one() } else { A::one() };
+    if A::IS_COMPLEX {
+        A::try_from_complex(A::cis(t * A::PI)).unwrap() * sign
+    } else {
+        sign
+    }
+}
+
+/// `2^e u` for a unit `u`, of absolute value in `[1/16, 16]` for `e` in `[-4, 4]`
+pub(crate) fn well_conditioned_from<A: Scalar>(u: A, e: f64) -> A {
+    u.mul_real(A::real(Float::exp2(e)))
+}
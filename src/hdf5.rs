@@ -0,0 +1,49 @@
+//! Reading and writing scalars of HDF5 files with [hdf5-metno](https://docs.rs/hdf5-metno)
+//!
+//! Real numbers are native IEEE floats, and complex numbers are the compound type
+//! `{r, i}` of two floats, the convention of h5py, so that datasets written by NumPy or
+//! Julia can be read as `c32` or `c64` and vice versa. The datatypes come from `H5Type`,
+//! so the generic methods of hdf5-metno also accept arrays of any [Hdf5Scalar].
+//!
+//! ```
+//! use cauchy::{c64, hdf5::{datatype, read_vec, write_slice}};
+//! use hdf5::types::{FloatSize, TypeDescriptor};
+//!
+//! assert_eq!(
+//!     datatype::<f32>().unwrap().to_descriptor().unwrap(),
+//!     TypeDescriptor::Float(FloatSize::U4)
+//! );
+//!
+//! let path = std::env::temp_dir().join("cauchy-hdf5-doctest.h5");
+//! let file = hdf5::File::create(&path).unwrap();
+//! let zs = [c64::new(1.0, 2.0), c64::new(-3.0, 0.5)];
+//! write_slice(&file, "z", &zs).unwrap();
+//! assert_eq!(read_vec::<c64>(&file, "z").unwrap(), zs);
+//! // a dataset of complex numbers is no dataset of real numbers
+//! assert!(read_vec::<f64>(&file, "z").is_err());
+//! # std::fs::remove_file(&path).unwrap();
+//! ```
+
+use crate::Scalar;
+use ::hdf5::{Dataset, Datatype, Group, H5Type, Result};
+
+/// [Scalar] with an HDF5 datatype
+pub trait Hdf5Scalar: Scalar + H5Type {}
+
+impl<A: Scalar + H5Type> Hdf5Scalar for A {}
+
+/// Native HDF5 datatype of `A`, the compound `{r, i}` for complex numbers
+pub fn datatype<A: Hdf5Scalar>() -> Result<Datatype> {
+    Datatype::from_type::<A>()
+}
+
+/// Create the one-dimensional dataset `name` in `group` holding `xs`
+pub fn write_slice<A: Hdf5Scalar>(group: &Group, name: &str, xs: &[A]) -> Result<Dataset> {
+    group.new_dataset_builder().with_data(xs).create(name)
+}
+
+/// Read the dataset `name` of `group` in memory order, failing unless its datatype
+/// converts into that of `A`
+pub fn read_vec<A: Hdf5Scalar>(group: &Group, name: &str) -> Result<Vec<A>> {
+    group.dataset(name)?.read_raw()
+}
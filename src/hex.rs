@@ -0,0 +1,163 @@
+//! Hexadecimal floating-point literals like `0x1.8p+1` for bit-exact round-tripping
+
+use crate::{parse::ParseScalarError, Scalar};
+use num_traits::Float;
+
+/// Format a real number as `[-]0x1.<hex digits>p<exponent>`, or `inf`, `-inf` and `NaN`
+#[cfg(feature = "std")]
+pub(crate) fn format<T: Float>(x: T) -> String {
+    if x.is_nan() {
+        return "NaN".into();
+    }
+    let sign = if x.is_sign_negative() { "-" } else { "" };
+    if x.is_infinite() {
+        return format!("{}inf", sign);
+    }
+    let (mantissa, exp, _) = x.integer_decode();
+    if mantissa == 0 {
+        return format!("{}0x0p+0", sign);
+    }
+    // normalize the leading bit into the most significant position, which also handles subnormals
+    let lz = mantissa.leading_zeros();
+    let exp = exp as i32 + 63 - lz as i32;
+    let fract = format!("{:016x}", mantissa << lz << 1);
+    let fract = fract.trim_end_matches('0');
+    if fract.is_empty() {
+        format!("{}0x1p{:+}", sign, exp)
+    } else {
+        format!("{}0x1.{}p{:+}", sign, fract, exp)
+    }
+}
+
+/// Round `(mantissa + sticky) * 2^exp` to nearest even with the precision of `T`
+fn round<T: Scalar<Real = T> + Float>(mantissa: u64, sticky: bool, exp: i64) -> T {
+    if mantissa == 0 {
+        return T::zero();
+    }
+    let (one, _, _) = T::one().integer_decode();
+    let digits = 64 - one.leading_zeros() as i64;
+    let (_, min_ulp, _) = T::min_positive_value().integer_decode();
+    let lead = exp + 63 - mantissa.leading_zeros() as i64;
+    let ulp = (lead - digits + 1).max(min_ulp as i64);
+    let drop = ulp - exp;
+    let (q, exp) = if drop <= 0 {
+        (mantissa, exp)
+    } else if drop > 64 {
+        (0, ulp)
+    } else {
+        let (q, rem, half) = if drop == 64 {
+            (0, mantissa, 1 << 63)
+        } else {
+            (
+                mantissa >> drop,
+                mantissa & ((1 << drop) - 1),
+                1 << (drop - 1),
+            )
+        };
+        let up = rem > half || (rem == half && (sticky || q & 1 == 1));
+        (q + up as u64, ulp)
+    };
+    let exp = exp.max(i32::MIN as i64).min(i32::MAX as i64) as i32;
+    T::real(q).ldexp(exp)
+}
+
+/// Parse a real number from a hexadecimal literal like `-0x1.8p+1`, `inf` or `NaN`
+fn parse_real<T: Scalar<Real = T> + Float>(s: &str) -> Result<T, ParseScalarError> {
+    let s = s.trim();
+    let (neg, body) = match s.as_bytes().first() {
+        Some(b'+') => (false, &s[1..]),
+        Some(b'-') => (true, &s[1..]),
+        _ => (false, s),
+    };
+    let x = if body.eq_ignore_ascii_case("inf") || body.eq_ignore_ascii_case("infinity") {
+        T::infinity()
+    } else if body.eq_ignore_ascii_case("nan") {
+        T::nan()
+    } else {
+        let body = body
+            .strip_prefix("0x")
+            .or_else(|| body.strip_prefix("0X"))
+            .ok_or_else(ParseScalarError::invalid)?;
+        let (digits, exp) = match body.find(['p', 'P']) {
+            Some(k) => {
+                let exp: i64 = body[k + 1..]
+                    .parse()
+                    .or_else(|_| saturate_exponent(&body[k + 1..]))?;
+                // far beyond the range of any precision, so that the arithmetic of `round`
+                // cannot overflow
+                let exp = exp.clamp(-MAX_EXPONENT, MAX_EXPONENT);
+                (&body[..k], exp)
+            }
+            None => (body, 0),
+        };
+        let (int, fract) = match digits.find('.') {
+            Some(k) => (&digits[..k], &digits[k + 1..]),
+            None => (digits, ""),
+        };
+        if int.is_empty() && fract.is_empty() {
+            return Err(ParseScalarError::invalid());
+        }
+        let mut mantissa = 0_u64;
+        let mut sticky = false;
+        let mut shift = 0_i64;
+        for (c, is_fract) in int
+            .chars()
+            .map(|c| (c, false))
+            .chain(fract.chars().map(|c| (c, true)))
+        {
+            let d = c.to_digit(16).ok_or_else(ParseScalarError::invalid)? as u64;
+            if mantissa >> 60 == 0 {
+                mantissa = mantissa << 4 | d;
+                if is_fract {
+                    shift -= 4;
+                }
+            } else {
+                sticky |= d != 0;
+                if !is_fract {
+                    shift += 4;
+                }
+            }
+        }
+        round(mantissa, sticky, exp + shift)
+    };
+    Ok(if neg { -x } else { x })
+}
+
+/// Bound of the binary exponents passed on to [round], which give infinity or zero beyond it
+const MAX_EXPONENT: i64 = 1 << 32;
+
+/// Exponents too large for `i64` still parse, as infinity or zero after rounding
+fn saturate_exponent(s: &str) -> Result<i64, ParseScalarError> {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseScalarError::invalid());
+    }
+    Ok(if s.starts_with('-') {
+        -MAX_EXPONENT
+    } else {
+        MAX_EXPONENT
+    })
+}
+
+/// Split a string like `0x1p+0` or `0x1p+0-0x1.8p-1i` into the real and imaginary parts
+pub(crate) fn parse_parts<T: Scalar<Real = T> + Float>(
+    s: &str,
+) -> Result<(T, T), ParseScalarError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseScalarError::empty());
+    }
+    let body = match s.strip_suffix('i') {
+        Some(body) => body,
+        None => return Ok((parse_real(s)?, T::zero())),
+    };
+    // the imaginary part starts at the last sign which is not in an exponent
+    let bytes = body.as_bytes();
+    let split = (1..bytes.len())
+        .rev()
+        .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'p' | b'P'));
+    match split {
+        Some(k) => Ok((parse_real(&body[..k])?, parse_real(&body[k..])?)),
+        None => Ok((T::zero(), parse_real(body)?)),
+    }
+}
@@ -37,45 +37,492 @@
 //! }
 //! ```
 
-use num_complex::Complex;
-use num_traits::{Float, FromPrimitive, NumAssign, NumCast, NumOps, ToPrimitive, Zero};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::convert::TryInto;
+use core::fmt::Debug;
+#[cfg(feature = "fmt")]
+use core::fmt::{Display, LowerExp, UpperExp};
+use core::hash::Hash;
+use core::iter::{Product, Sum};
+use core::num::FpCategory;
+use core::ops::Neg;
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{
+    float::TotalOrder, Float, FloatConst, FromPrimitive, NumAssign, NumCast, NumOps, ToPrimitive,
+    Zero,
+};
 use rand::{distributions::Standard, prelude::*};
+use rand_distr::{Exp, Gamma, StandardNormal};
 use serde::{Deserialize, Serialize};
-use std::fmt::{Debug, Display, LowerExp, UpperExp};
-use std::iter::{Product, Sum};
-use std::ops::Neg;
+
+mod adapter;
+mod arch;
+mod bessel;
+mod div;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod generate;
+mod hex;
+mod mixed;
+mod narrow;
+mod newtype;
+mod parse;
+#[cfg(feature = "rand09")]
+mod rand09;
+mod special;
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod blas;
+#[cfg(feature = "cuda")]
+pub mod cuda;
+pub mod distributions;
+#[cfg(feature = "std")]
+pub mod dyn_scalar;
+#[cfg(feature = "hdf5")]
+pub mod hdf5;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "bytemuck")]
+pub mod pod;
+pub mod polar;
+pub mod poly;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "pyo3")]
+pub mod pyo3;
+pub mod qmc;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+pub mod scalar_ref;
+#[cfg(feature = "std")]
+pub mod serde_string;
+#[cfg(feature = "simba")]
+pub mod simba;
+pub mod simd;
+pub mod slice;
+pub mod stats;
+pub mod sum;
+pub mod test_suite;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "zerocopy")]
+pub mod zerocopy;
+
+pub use bessel::Bessel;
+pub use distributions::Componentwise;
+pub use mixed::MixedOps;
+pub use narrow::NarrowError;
+pub use parse::ParseScalarError;
+pub use polar::Polar;
+pub use qmc::Halton;
+#[cfg(feature = "rand09")]
+pub use rand09::Rng09;
+pub use simd::SimdMath;
 
 pub use num_complex::Complex32 as c32;
 pub use num_complex::Complex64 as c64;
+pub use num_traits::{ConstOne, ConstZero};
 
-pub trait Scalar:
+/// Dependencies used in the expansion of [impl_scalar_newtype]
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "approx")]
+    pub use approx;
+    #[cfg(feature = "ndarray")]
+    pub use ndarray;
+    pub use num_traits;
+    pub use rand;
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+    pub use serde;
+    #[cfg(feature = "std")]
+    pub use std::string::String;
+}
+
+/// Create a scalar of type `A: Scalar` from a real or complex literal
+///
+/// Complex literals are written as `a + b i`, `a - b i` or `b i`,
+/// and panic if `A` is a real type and `b` is not zero.
+///
+/// ```
+/// use cauchy::*;
+///
+/// fn half<A: Scalar>() -> A {
+///     scalar!(A, 0.5)
+/// }
+/// assert_eq!(half::<f32>(), 0.5);
+/// assert_eq!(scalar!(c64, 1.5 + 2.5 i), c64::new(1.5, 2.5));
+/// assert_eq!(scalar!(c32, -1 - 2 i), c32::new(-1.0, -2.0));
+/// assert_eq!(scalar!(c64, 3.0 i), c64::new(0.0, 3.0));
+/// ```
+#[macro_export]
+macro_rules! scalar {
+    (@complex $t:ty, $re:expr, $im:expr) => {
+        <$t as $crate::Scalar>::try_from_complex(<$t as $crate::Scalar>::complex($re, $im))
+            .expect("non-zero imaginary part for real scalar")
+    };
+    ($t:ty, $re:literal + $im:literal i) => {
+        $crate::scalar!(@complex $t, $re as f64, $im as f64)
+    };
+    ($t:ty, $re:literal - $im:literal i) => {
+        $crate::scalar!(@complex $t, $re as f64, -($im as f64))
+    };
+    ($t:ty, $im:literal i) => {
+        $crate::scalar!(@complex $t, 0.0, $im as f64)
+    };
+    ($t:ty, $re:expr) => {
+        <$t as $crate::Scalar>::from_real(<$t as $crate::Scalar>::real($re))
+    };
+}
+
+/// Formatting supertraits of [NumScalar], `Display + LowerExp + UpperExp` with the `fmt` feature
+///
+/// Without the default `fmt` feature it is implemented for all types, so that types which
+/// cannot be formatted, such as SIMD vectors, can implement [Scalar].
+#[cfg(feature = "fmt")]
+pub trait ScalarFmt: Display + LowerExp + UpperExp {}
+#[cfg(feature = "fmt")]
+impl<T: Display + LowerExp + UpperExp> ScalarFmt for T {}
+
+/// Formatting supertraits of [NumScalar], `Display + LowerExp + UpperExp` with the `fmt` feature
+///
+/// Without the default `fmt` feature it is implemented for all types, so that types which
+/// cannot be formatted, such as SIMD vectors, can implement [Scalar].
+#[cfg(not(feature = "fmt"))]
+pub trait ScalarFmt {}
+#[cfg(not(feature = "fmt"))]
+impl<T> ScalarFmt for T {}
+
+/// Comparisons of approx, `AbsDiffEq + RelativeEq + UlpsEq` with tolerances of type `E`,
+/// when the `approx` feature is enabled
+///
+/// [Scalar] requires it with `E = Self::Real`. Complex numbers are compared component-wise,
+/// that is both the real and the imaginary parts have to be within the tolerance.
+///
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// use approx::assert_relative_eq;
+/// use cauchy::*;
+///
+/// fn check<A: Scalar>(x: A) {
+///     assert_relative_eq!(x.sqrt() * x.sqrt(), x, max_relative = A::real(1e-6));
+/// }
+/// check(2.0_f32);
+/// check(c64::new(-1.0, 2.0));
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+pub trait ScalarApprox<E>: AbsDiffEq<Epsilon = E> + RelativeEq + UlpsEq {}
+#[cfg(feature = "approx")]
+impl<T: AbsDiffEq<Epsilon = E> + RelativeEq + UlpsEq, E> ScalarApprox<E> for T {}
+
+/// Comparisons of approx, `AbsDiffEq + RelativeEq + UlpsEq` with tolerances of type `E`,
+/// when the `approx` feature is enabled
+#[cfg(not(feature = "approx"))]
+pub trait ScalarApprox<E> {}
+#[cfg(not(feature = "approx"))]
+impl<T, E> ScalarApprox<E> for T {}
+
+/// Zero-copy archives of rkyv, `Archive<Archived = Self> + Deserialize<Self, Infallible>`,
+/// when the `rkyv` feature is enabled
+///
+/// The archived form of every scalar is the scalar itself, so that an archived buffer of
+/// scalars can be used in place as `&[A]`. Serializing a scalar does not write anything
+/// besides the scalar itself for every serializer.
+///
+/// ```
+/// # #[cfg(feature = "rkyv")] {
+/// use cauchy::*;
+/// use rkyv::vec::ArchivedVec;
+///
+/// fn total<A: Scalar>(archived: &ArchivedVec<A>) -> A {
+///     archived.as_slice().iter().copied().sum()
+/// }
+///
+/// let bytes = rkyv::to_bytes::<_, 256>(&vec![c64::new(1.0, 2.0), c64::new(3.0, 4.0)]).unwrap();
+/// // SAFETY: the bytes are the archive of a `Vec<c64>` created above
+/// let archived = unsafe { rkyv::archived_root::<Vec<c64>>(&bytes) };
+/// assert_eq!(total(archived), c64::new(4.0, 6.0));
+/// # }
+/// ```
+#[cfg(feature = "rkyv")]
+pub trait ScalarArchive:
+    Sized + rkyv::Archive<Archived = Self> + rkyv::Deserialize<Self, rkyv::Infallible>
+{
+}
+#[cfg(feature = "rkyv")]
+impl<T: rkyv::Archive<Archived = T> + rkyv::Deserialize<T, rkyv::Infallible>> ScalarArchive for T {}
+
+/// Zero-copy archives of rkyv, `Archive<Archived = Self> + Deserialize<Self, Infallible>`,
+/// when the `rkyv` feature is enabled
+#[cfg(not(feature = "rkyv"))]
+pub trait ScalarArchive {}
+#[cfg(not(feature = "rkyv"))]
+impl<T> ScalarArchive for T {}
+
+/// Numbers with arithmetic, conversions, formatting, serialization and random generation,
+/// implemented for the primitive integers as well as for every [Scalar]
+///
+/// ```
+/// use cauchy::*;
+/// use rand::prelude::*;
+///
+/// fn weighted_sum<A: NumScalar>(weights: &[A], counts: &[A]) -> A {
+///     weights.iter().zip(counts).map(|(&w, &c)| w * c).sum()
+/// }
+/// assert_eq!(weighted_sum(&[1_u64, 2, 3], &[4, 5, 6]), 32);
+/// assert_eq!(weighted_sum(&[0.5, 0.25], &[2.0, 4.0]), 2.0);
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let _ = (u8::rand(&mut rng), c64::rand(&mut rng));
+/// ```
+pub trait NumScalar:
     NumAssign
+    + ConstZero
+    + ConstOne
     + FromPrimitive
     + NumCast
-    + Neg<Output = Self>
     + Copy
     + Clone
-    + Display
+    + PartialEq
+    + ScalarFmt
     + Debug
-    + LowerExp
-    + UpperExp
     + Sum
     + Product
     + Serialize
     + for<'de> Deserialize<'de>
+    + Send
+    + Sync
     + 'static
 {
-    type Real: Scalar<Real = Self::Real, Complex = Self::Complex>
+    /// Generate an random number from
+    /// [rand::distributions::Standard](https://docs.rs/rand/0.7.2/rand/distributions/struct.Standard.html)
+    ///
+    /// Any `rand_core::RngCore`, such as a hardware generator, can be used as `rng`,
+    /// also when the crate is built without the `std` feature.
+    fn rand(rng: &mut impl Rng) -> Self;
+}
+
+macro_rules! impl_num_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl NumScalar for $t {
+                fn rand(rng: &mut impl Rng) -> Self {
+                    rng.sample(Standard)
+                }
+            }
+        )*
+    };
+}
+
+impl_num_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_num_scalar!(f32, f64, c32, c64);
+
+pub trait Scalar:
+    NumScalar + Neg<Output = Self> + ScalarApprox<<Self as Scalar>::Real> + ScalarArchive
+{
+    type Real: RealScalar<Real = Self::Real, Complex = Self::Complex>
         + NumOps<Self::Real, Self::Real>
         + Float;
-    type Complex: Scalar<Real = Self::Real, Complex = Self::Complex>
+    type Complex: ComplexScalar<Real = Self::Real, Complex = Self::Complex>
         + NumOps<Self::Real, Self::Complex>
         + NumOps<Self::Complex, Self::Complex>;
+    /// Type of the same kind with at least the precision of `Self`, e.g. `f64` for `f32`,
+    /// for accumulating intermediate results
+    type Widened: Scalar;
+
+    /// Whether `Self` is a complex type, e.g. for workspace sizes which differ as in LAPACK
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// fn lwork<A: Scalar>(n: usize) -> usize {
+    ///     if A::IS_COMPLEX { 2 * n } else { 3 * n }
+    /// }
+    /// assert_eq!(lwork::<f64>(10), 30);
+    /// assert_eq!(lwork::<c64>(10), 20);
+    /// assert_eq!(c32::COMPONENTS, 2);
+    /// ```
+    const IS_COMPLEX: bool;
+    /// Number of real components, 1 for real and 2 for complex types
+    const COMPONENTS: usize;
+    /// The imaginary unit, as [Scalar::i] for const contexts
+    ///
+    /// Together with `ZERO` and `ONE` of [NumScalar], it allows constant tables in generic code:
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// struct Table<A: Scalar>(A);
+    ///
+    /// impl<A: Scalar> Table<A> {
+    ///     const UNITS: [A::Complex; 3] = [A::Complex::ZERO, A::Complex::ONE, A::I];
+    ///     const HALF_PI: A::Real = A::PI;
+    /// }
+    /// assert_eq!(Table::<f32>::UNITS[2], c32::new(0.0, 1.0));
+    /// assert_eq!(Table::<c64>::HALF_PI, std::f64::consts::PI);
+    /// assert_eq!(f64::PI, std::f64::consts::PI);
+    /// ```
+    const I: Self::Complex;
+    /// Archimedes' constant `pi`
+    const PI: Self::Real;
+    /// Euler's number `e`
+    const E: Self::Real;
+    /// Machine epsilon, the difference between 1 and the next larger number
+    const EPSILON: Self::Real;
 
     /// Create a new real number
     fn real<T: ToPrimitive>(re: T) -> Self::Real;
     /// Create a new complex number
     fn complex<T: ToPrimitive>(re: T, im: T) -> Self::Complex;
+    /// Parse a number from strings like `1.5`, `-2e-3+4.5i`, `3j`, or `(1, 2)`
+    ///
+    /// Real numbers are rejected if the imaginary part is non-zero.
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// assert_eq!(c64::parse("-2e-3+4.5i"), Ok(c64::new(-2e-3, 4.5)));
+    /// assert_eq!(c64::parse("(1, 2)"), Ok(c64::new(1.0, 2.0)));
+    /// assert_eq!(f64::parse("3j").ok(), None);
+    /// ```
+    fn parse(s: &str) -> Result<Self, ParseScalarError>;
+    /// Format as hexadecimal floating-point literal like `0x1.8p+1` or `0x1p+0-0x1.8p-1i`,
+    /// which [Scalar::from_hex_str] parses back bit-exactly
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// assert_eq!(3.0_f64.to_hex_string(), "0x1.8p+1");
+    /// assert_eq!(c32::new(1.0, -0.75).to_hex_string(), "0x1p+0-0x1.8p-1i");
+    /// assert_eq!(f32::from_hex_str("0x1.8p+1"), Ok(3.0));
+    /// assert_eq!(c64::from_hex_str("0x1.0p0+0x1.8p-1i"), Ok(c64::new(1.0, 0.75)));
+    ///
+    /// let x = 0.1_f64;
+    /// assert_eq!(f64::from_hex_str(&x.to_hex_string()), Ok(x));
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_hex_string(&self) -> String;
+    /// Format with the shortest decimal digits which [Scalar::parse] reads back exactly,
+    /// switching to scientific notation when it is shorter
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// assert_eq!(0.1_f64.to_shortest_string(), "0.1");
+    /// assert_eq!(1e300_f64.to_shortest_string(), "1e300");
+    /// assert_eq!(c32::new(1.5, -2e-9).to_shortest_string(), "1.5-2e-9i");
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_shortest_string(&self) -> String;
+    /// Parse a hexadecimal floating-point literal, rounding to nearest if it has too many digits
+    ///
+    /// Exponents out of range give infinity or zero, whichever their sign implies:
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// assert_eq!(f64::from_hex_str("0x1p9223372036854775807"), Ok(f64::INFINITY));
+    /// assert_eq!(f64::from_hex_str("0x1p-9223372036854775808"), Ok(0.0));
+    /// assert_eq!(c32::from_hex_str("0x1p-99999999999999999999i"), Ok(c32::new(0.0, 0.0)));
+    /// ```
+    fn from_hex_str(s: &str) -> Result<Self, ParseScalarError>;
+    /// Create a new real number, or `None` if `re` cannot be represented
+    fn try_real<T: ToPrimitive>(re: T) -> Option<Self::Real>;
+    /// Create a new complex number, or `None` if `re` or `im` cannot be represented
+    fn try_complex<T: ToPrimitive>(re: T, im: T) -> Option<Self::Complex>;
+    /// Convert from a complex number, or `None` for real types if the imaginary part is not zero
+    fn try_from_complex(c: Self::Complex) -> Option<Self>;
+
+    /// Convert into [Scalar::Widened] without loss
+    fn widen(self) -> Self::Widened;
+    /// Round from [Scalar::Widened] into `Self`
+    fn narrow(w: Self::Widened) -> Self;
+
+    /// Convert into another scalar type, e.g. between `f32` and `f64` or `f64` and `c64`
+    ///
+    /// Complex numbers are converted into real types only if the imaginary part is zero,
+    /// and `None` is returned otherwise. Values which overflow the target precision
+    /// become infinite.
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// assert_eq!(1.5_f32.cast::<c64>(), Some(c64::new(1.5, 0.0)));
+    /// assert_eq!(c64::new(1.0, 0.0).cast::<f32>(), Some(1.0));
+    /// assert_eq!(c64::new(1.0, 1.0).cast::<f32>(), None);
+    /// ```
+    fn cast<B: Scalar>(self) -> Option<B> {
+        B::try_from_complex(B::try_complex(self.re(), self.im())?)
+    }
+    /// Convert into another scalar type as [Scalar::cast],
+    /// but drop the imaginary part when converting complex numbers into real types
+    fn cast_lossy<B: Scalar>(self) -> B {
+        self.cast()
+            .unwrap_or_else(|| B::from_real(B::real(self.re())))
+    }
+    /// Convert into another scalar type as [Scalar::cast], but fail if the value overflows
+    /// or its relative rounding error exceeds `tol`
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// assert_eq!(0.5_f64.try_narrow::<f32>(0.0), Ok(0.5));
+    /// assert!(0.1_f64.try_narrow::<f32>(1e-6).is_ok());
+    /// assert!(0.1_f64.try_narrow::<f32>(1e-12).is_err());
+    /// assert!(1e300_f64.try_narrow::<f32>(1.0).is_err());
+    /// assert!(c64::new(1.0, 1.0).try_narrow::<f32>(1.0).is_err());
+    /// ```
+    fn try_narrow<B: Scalar>(self, tol: Self::Real) -> Result<B, NarrowError> {
+        let b: B = self.cast().ok_or_else(NarrowError::not_real)?;
+        let back = Self::try_complex(b.re(), b.im()).ok_or_else(NarrowError::overflow)?;
+        let err = Scalar::abs(self.as_c() - back);
+        let finite = Float::is_finite(self.re()) && Float::is_finite(self.im());
+        if finite && !Float::is_finite(err) {
+            return Err(NarrowError::overflow());
+        }
+        if err > tol * Scalar::abs(self) {
+            return Err(NarrowError::inexact());
+        }
+        Ok(b)
+    }
+
+    /// Create a unit complex number `cos(theta) + i sin(theta)`
+    fn cis(theta: Self::Real) -> Self::Complex;
+    /// Create a complex number `r (cos(theta) + i sin(theta))` from its magnitude and phase
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// let z = f64::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+    /// assert!((z - c64::new(0.0, 2.0)).norm() < 1e-15);
+    /// ```
+    fn from_polar(r: Self::Real, theta: Self::Real) -> Self::Complex {
+        Self::cis(theta).mul_real(r)
+    }
+    /// The imaginary unit `i` in the precision of `Self`
+    fn i() -> Self::Complex;
+    /// Create a complex number from a `(re, im)` pair
+    fn from_parts(parts: (Self::Real, Self::Real)) -> Self::Complex;
+    /// Split into a `(re, im)` pair, the inverse of [Scalar::from_parts]
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// let (re, im) = c64::new(1.0, 2.0).into_parts();
+    /// assert_eq!(f64::from_parts((re, im)), c64::new(1.0, 2.0));
+    /// assert_eq!(3.0_f32.into_parts(), (3.0, 0.0));
+    /// ```
+    fn into_parts(self) -> (Self::Real, Self::Real) {
+        (self.re(), self.im())
+    }
 
     fn from_real(re: Self::Real) -> Self;
 
@@ -87,12 +534,76 @@ pub trait Scalar:
     fn add_complex(self, im: Self::Complex) -> Self::Complex;
     fn sub_complex(self, im: Self::Complex) -> Self::Complex;
     fn mul_complex(self, im: Self::Complex) -> Self::Complex;
+    /// Division by a complex number
+    ///
+    /// This uses Smith's algorithm, which does not overflow or underflow
+    /// spuriously unlike the `/` operator of `num_complex::Complex`:
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// let a = c32::new(1e30, 1e30);
+    /// assert_eq!(a.div_complex(a * 2.0), c32::new(0.5, 0.0));
+    /// ```
     fn div_complex(self, im: Self::Complex) -> Self::Complex;
 
+    /// Linear interpolation `self + t (other - self)`
+    ///
+    /// This is exact at `t = 0` and `t = 1`, and monotone in `t` for real numbers.
+    /// Complex numbers are interpolated component-wise.
+    fn lerp(self, other: Self, t: Self::Real) -> Self;
+    /// Midpoint `(self + other) / 2` without intermediate overflow
+    fn midpoint(self, other: Self) -> Self;
+
+    /// Division which returns `None` if `rhs` is zero or the quotient has a NaN component
+    ///
+    /// Complex numbers are divided as in [Scalar::div_complex], and infinite operands
+    /// are otherwise handled as IEEE 754 does. NaN operands always result in `None`.
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let q = self / rhs;
+        if q.re().is_nan() || q.im().is_nan() {
+            None
+        } else {
+            Some(q)
+        }
+    }
+
+    /// Remainder of the truncated division `self - trunc(self / rhs) * rhs`,
+    /// which has the sign of `self`, as C's `fmod`
+    ///
+    /// This is applied to the real and imaginary parts separately for complex numbers.
+    fn fmod(self, rhs: Self::Real) -> Self;
+    /// Non-negative remainder of the Euclidean division, in `[0, |rhs|)`
+    ///
+    /// This is applied to the real and imaginary parts separately for complex numbers.
+    fn rem_euclid(self, rhs: Self::Real) -> Self;
+
+    /// Split into the integral part truncated toward zero and the fractional part,
+    /// both with the sign of `self`
+    ///
+    /// This is applied to the real and imaginary parts separately for complex numbers.
+    fn modf(self) -> (Self, Self);
+
     fn pow(self, n: Self) -> Self;
     fn powi(self, n: i32) -> Self;
     fn powf(self, n: Self::Real) -> Self;
     fn powc(self, n: Self::Complex) -> Self::Complex;
+    /// Real power with the principal complex value, e.g. `(-8)^(1/3) = 1 + i sqrt(3)`
+    /// while `powf` returns NaN for negative real bases
+    fn powf_c(self, n: Self::Real) -> Self::Complex;
+
+    /// Decompose into a mantissa and exponent `self = m 2^e`,
+    /// where the mantissa satisfies `0.5 <= |m| < 1` for non-zero finite numbers
+    ///
+    /// For complex numbers, the exponent is that of the component with larger magnitude and
+    /// both components are scaled by it, so that it is exact unless the components differ in
+    /// magnitude by the whole exponent range.
+    fn frexp(self) -> (Self, i32);
+    /// Multiply by an integral power of two `self 2^exp`, component-wise for complex numbers
+    fn ldexp(self, exp: i32) -> Self;
 
     /// Real part
     fn re(&self) -> Self::Real;
@@ -100,17 +611,79 @@ pub trait Scalar:
     fn im(&self) -> Self::Real;
     /// As a complex number
     fn as_c(&self) -> Self::Complex;
+    /// Multiply by the imaginary unit, `i self`
+    fn mul_i(self) -> Self::Complex;
+    /// Divide by the imaginary unit, `-i self`
+    fn div_i(self) -> Self::Complex;
     /// Complex conjugate
     fn conj(&self) -> Self;
+    /// Projection onto the Riemann sphere, as C's `cproj`
+    ///
+    /// Complex numbers with an infinite component are mapped to `inf + i0`,
+    /// where the zero has the sign of the imaginary part, and all other numbers are unchanged.
+    fn proj(self) -> Self;
+    /// Product with the complex conjugate of self, `conj(self) * other`
+    fn conj_mul(self, other: Self) -> Self;
 
     /// Absolute value
+    ///
+    /// The modulus of complex numbers is evaluated without overflow or underflow
+    /// of intermediate values:
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// let a = c32::new(1e30, 1e30).abs();
+    /// assert!((a / 1e30 - std::f32::consts::SQRT_2).abs() < 1e-6);
+    ///
+    /// let b = c64::new(3e-300, 4e-300).abs();
+    /// assert!((b / 5e-300 - 1.0).abs() < 1e-15);
+    /// ```
     fn abs(self) -> Self::Real;
+
+    /// Whether the number is neither zero, infinite, subnormal, nor NaN
+    ///
+    /// A complex number is normal if any of its components is normal.
+    fn is_normal(&self) -> bool;
+    /// Whether the number is subnormal
+    ///
+    /// A complex number is subnormal if any of its components is subnormal.
+    fn is_subnormal(&self) -> bool;
+    /// Whether the imaginary part is within `tol` of zero, `|im| <= tol`
+    fn is_real(&self, tol: Self::Real) -> bool {
+        Float::abs(self.im()) <= tol
+    }
+    /// Whether the real part is within `tol` of zero, `|re| <= tol`
+    fn is_imaginary(&self, tol: Self::Real) -> bool {
+        Float::abs(self.re()) <= tol
+    }
+
+    /// Fused multiply-add `self * a + b`, rounded once for real numbers
+    /// and once per product for complex numbers
+    fn mul_add(self, a: Self, b: Self) -> Self;
     /// Sqaure of absolute value
     fn square(self) -> Self::Real;
+    /// Absolute value of the difference `|self - other|`
+    ///
+    /// This does not overflow unless the result itself is not representable.
+    fn abs_diff(self, other: Self) -> Self::Real {
+        (self - other).abs()
+    }
 
     fn sqrt(self) -> Self;
+    /// Square root which returns `None` for negative or NaN real numbers
+    ///
+    /// This is always `Some` for complex numbers.
+    fn checked_sqrt(self) -> Option<Self>;
+    /// Reciprocal square root `1 / sqrt(x)`
+    ///
+    /// This may use a hardware estimate refined by a Newton step,
+    /// and then deviates from `1 / sqrt(x)` in the last bits.
+    fn rsqrt(self) -> Self;
     fn exp(self) -> Self;
     fn ln(self) -> Self;
+    /// Logarithm with respect to an arbitrary base
+    fn log(self, base: Self::Real) -> Self;
     fn sin(self) -> Self;
     fn cos(self) -> Self;
     fn tan(self) -> Self;
@@ -123,10 +696,303 @@ pub trait Scalar:
     fn asinh(self) -> Self;
     fn acosh(self) -> Self;
     fn atanh(self) -> Self;
+    /// Error function
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// // erf(iy) = i erfi(y), with erfi(20) = 1.474797539628784e172
+    /// let z = c64::new(0.0, 20.0).erf();
+    /// assert!((z.im / 1.474797539628784e172 - 1.0).abs() < 1e-12);
+    /// ```
+    fn erf(self) -> Self;
+    /// Complementary error function `1 - erf(x)`
+    fn erfc(self) -> Self;
+    /// Gamma function
+    fn gamma(self) -> Self;
+    /// Logarithm of the gamma function
+    ///
+    /// This is `ln|Γ(x)|` for real numbers, and the principal branch of
+    /// log-gamma, analytic except on the negative real axis, for complex numbers.
+    fn ln_gamma(self) -> Self;
+    /// `ln(exp(self) + exp(other))` without intermediate overflow
+    fn logaddexp(self, other: Self) -> Self;
+    /// Logistic sigmoid function `1 / (1 + exp(-x))`
+    fn sigmoid(self) -> Self {
+        // evaluate exp only for arguments which do not overflow
+        if self.re() >= Self::Real::zero() {
+            Self::one() / (Self::one() + (-self).exp())
+        } else {
+            let e = self.exp();
+            e / (Self::one() + e)
+        }
+    }
+    /// Logit function `ln(p / (1 - p))`, the inverse of [Scalar::sigmoid]
+    fn logit(self) -> Self {
+        (self / (Self::one() - self)).ln()
+    }
 
-    /// Generate an random number from
-    /// [rand::distributions::Standard](https://docs.rs/rand/0.7.2/rand/distributions/struct.Standard.html)
-    fn rand(rng: &mut impl Rng) -> Self;
+    /// Beta function `B(a, b) = Γ(a) Γ(b) / Γ(a + b)`
+    fn beta(a: Self, b: Self) -> Self;
+    /// Logarithm of the beta function
+    ///
+    /// This is `ln|B(a, b)|` for real numbers.
+    fn ln_beta(a: Self, b: Self) -> Self {
+        special::ln_beta(a, b)
+    }
+    /// Digamma function `ψ(x) = d/dx ln Γ(x)`
+    fn digamma(self) -> Self {
+        special::digamma(self)
+    }
+    /// Polygamma function `ψ^(n)(x)`, the `n`-th derivative of the digamma function
+    ///
+    /// Arguments of negative real part use the reflection formula, so that far from the
+    /// origin there is no long recurrence, and non-finite arguments give NaN.
+    ///
+    /// ```
+    /// use cauchy::Scalar;
+    ///
+    /// // ψ'(x) = π^2 / sin^2(πx) - ψ'(1 - x), close to π^2 at half-integers far from 0
+    /// let pi2 = std::f64::consts::PI.powi(2);
+    /// assert!(((-1e8_f64 + 0.5).polygamma(1) - pi2).abs() < 1e-6);
+    /// // poles at the non-positive integers
+    /// assert!((-1e17_f64).polygamma(1).is_nan());
+    /// assert!(f64::NEG_INFINITY.polygamma(1).is_nan());
+    /// ```
+    fn polygamma(self, n: u32) -> Self {
+        special::polygamma(self, n)
+    }
+    /// Convert radians to degrees
+    ///
+    /// Complex numbers are scaled as a whole, i.e. both components are converted.
+    fn to_degrees(self) -> Self;
+    /// Convert degrees to radians
+    ///
+    /// Complex numbers are scaled as a whole, i.e. both components are converted.
+    fn to_radians(self) -> Self;
+    /// Sine and cosine, computed together
+    fn sin_cos(self) -> (Self, Self);
+    /// `exp(i self)`
+    fn expi(self) -> Self::Complex;
+
+    /// Unnormalized sinc function `sin(x) / x`, where `sinc(0) == 1`
+    fn sinc(self) -> Self {
+        // sin(x) / x = 1 - x^2 / 6 + x^4 / 120 - ..., and the x^4 term is
+        // below the rounding error while |x| < epsilon^(1/4)
+        if self.abs() < Float::sqrt(Float::sqrt(Self::Real::epsilon())) {
+            Self::one() - (self * self).div_real(Self::real(6))
+        } else {
+            self.sin() / self
+        }
+    }
+
+    /// Generate a random number from the distribution `dist`,
+    /// see [Componentwise] to lift distributions over real numbers to complex numbers
+    fn rand_using<D: Distribution<Self>>(rng: &mut impl Rng, dist: D) -> Self {
+        rng.sample(dist)
+    }
+    /// Generate a random number from the standard normal distribution,
+    /// independently for the real and imaginary parts of complex numbers
+    fn rand_normal(rng: &mut impl Rng) -> Self;
+    /// Generate a random number as [Scalar::rand], resampled until its absolute value
+    /// is at least the square root of the machine epsilon
+    fn rand_nonzero(rng: &mut impl Rng) -> Self {
+        let floor = Float::sqrt(Self::Real::epsilon());
+        loop {
+            let x = Self::rand(rng);
+            if Scalar::abs(x) >= floor {
+                return x;
+            }
+        }
+    }
+    /// Fill a slice with random numbers distributed as [Scalar::rand],
+    /// generating the random bits in bulk
+    fn fill_rand(slice: &mut [Self], rng: &mut impl Rng);
+    /// Generate the `index`-th number of a reproducible sequence in `[0, 1)`,
+    /// component-wise for complex numbers as [Scalar::rand]
+    ///
+    /// The sequence only depends on `seed`, and all types take the leading bits of the same
+    /// splitmix64 outputs, so that they agree up to their precision
+    /// and the real parts of complex numbers agree with real numbers.
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// let x = f64::rand_seeded(42, 7);
+    /// assert!((f32::rand_seeded(42, 7) as f64 - x).abs() < f32::EPSILON as f64);
+    /// assert_eq!(c64::rand_seeded(42, 7).re, x);
+    /// ```
+    fn rand_seeded(seed: u64, index: u64) -> Self;
+    /// Take the next point of a low-discrepancy sequence in `[0, 1)`,
+    /// component-wise for complex numbers as [Scalar::rand]
+    fn qrand(seq: &mut Halton) -> Self;
+    /// Generate a random number from the standard normal distribution for real numbers,
+    /// and from the circularly-symmetric complex normal distribution `CN(0, 1)`
+    /// with independent `N(0, 1/2)` real and imaginary parts for complex numbers
+    fn rand_cnormal(rng: &mut impl Rng) -> Self;
+    /// Generate a random number from the exponential distribution with rate `lambda`,
+    /// as the magnitude with a uniformly distributed phase for complex numbers
+    ///
+    /// Panics if `lambda` is negative or NaN.
+    fn rand_exp(rng: &mut impl Rng, lambda: Self::Real) -> Self;
+    /// Generate a random number from the gamma distribution with `shape` and `scale`,
+    /// as the magnitude with a uniformly distributed phase for complex numbers
+    ///
+    /// Panics if `shape` or `scale` is not positive.
+    fn rand_gamma(rng: &mut impl Rng, shape: Self::Real, scale: Self::Real) -> Self;
+    /// Generate a random number of unit modulus, `+1` or `-1` with equal probability
+    /// for real numbers and `e^{i theta}` with a uniformly distributed phase for complex numbers
+    fn rand_unit(rng: &mut impl Rng) -> Self;
+    /// Generate a random number uniformly distributed in `[-1, 1]` for real numbers
+    /// and in the closed unit disk for complex numbers
+    fn rand_disk(rng: &mut impl Rng) -> Self;
+    /// Generate a random number uniformly distributed in `[lo, hi)` for real numbers,
+    /// and in the annulus `lo <= |z| < hi` for complex numbers
+    ///
+    /// Panics if `lo >= hi`, or for complex numbers if `lo < 0`.
+    ///
+    /// ```
+    /// use cauchy::*;
+    /// use rand::prelude::*;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let z = c64::rand_range(&mut rng, 1.0, 2.0);
+    /// assert!(1.0 <= z.abs() && z.abs() < 2.0);
+    /// ```
+    fn rand_range(rng: &mut impl Rng, lo: Self::Real, hi: Self::Real) -> Self;
+}
+
+/// Memory layout of IEEE 754 floats and complex numbers over them, implemented for `f32`, `f64`,
+/// `c32`, `c64` and newtypes of them
+///
+/// It is separate from [Scalar] so that types whose layout is not fixed, such as
+/// [FloatAdapter](scalar_ref::FloatAdapter) over any `num_traits::Float`, can implement [Scalar].
+pub trait ScalarLayout: Scalar {
+    /// Raw bit representation, `u32` or `u64` for real numbers and a pair of them for complex numbers
+    type Bits: Copy + Eq + Hash + Debug + Send + Sync;
+    /// Byte representation, `[u8; 4]` or `[u8; 8]` for real numbers and twice as long,
+    /// with the real part first, for complex numbers
+    type Bytes: Copy + Eq + Hash + Debug + Default + AsRef<[u8]> + AsMut<[u8]> + Send + Sync;
+
+    /// View complex numbers as interleaved real and imaginary parts
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// let zs = [c64::new(1.0, 2.0), c64::new(3.0, 4.0)];
+    /// assert_eq!(f64::complex_as_real_slice(&zs), &[1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(f64::real_as_complex_slice(&[1.0, 2.0, 3.0, 4.0]), Some(&zs[..]));
+    /// assert_eq!(f64::real_as_complex_slice(&[1.0, 2.0, 3.0]), None);
+    /// ```
+    fn complex_as_real_slice(xs: &[Self::Complex]) -> &[Self::Real];
+    /// View complex numbers as interleaved real and imaginary parts, mutably
+    fn complex_as_real_slice_mut(xs: &mut [Self::Complex]) -> &mut [Self::Real];
+    /// View interleaved real and imaginary parts as complex numbers,
+    /// or `None` if the length is odd
+    fn real_as_complex_slice(xs: &[Self::Real]) -> Option<&[Self::Complex]>;
+    /// View interleaved real and imaginary parts as complex numbers mutably,
+    /// or `None` if the length is odd
+    fn real_as_complex_slice_mut(xs: &mut [Self::Real]) -> Option<&mut [Self::Complex]>;
+
+    /// Raw transmutation to bits, component-wise for complex numbers
+    fn to_bits(self) -> Self::Bits;
+    /// Raw transmutation from bits, the inverse of [ScalarLayout::to_bits]
+    fn from_bits(bits: Self::Bits) -> Self;
+    /// Memory representation in little-endian byte order, component-wise for complex numbers
+    ///
+    /// ```
+    /// use cauchy::*;
+    ///
+    /// let z = c32::new(1.0, -2.0);
+    /// assert_eq!(z.to_le_bytes(), [0, 0, 0x80, 0x3f, 0, 0, 0, 0xc0]);
+    /// assert_eq!(c32::from_le_bytes(z.to_le_bytes()), z);
+    /// assert_eq!(c32::from_be_bytes(z.to_be_bytes()), z);
+    /// ```
+    fn to_le_bytes(self) -> Self::Bytes;
+    /// Memory representation in big-endian byte order, component-wise for complex numbers
+    fn to_be_bytes(self) -> Self::Bytes;
+    /// Create from the little-endian memory representation, the inverse of [ScalarLayout::to_le_bytes]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    /// Create from the big-endian memory representation, the inverse of [ScalarLayout::to_be_bytes]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+/// Real scalars, `f32` and `f64`, with the operations of [Float] and the IEEE 754 total order
+///
+/// Bound generic code by it when it is only valid for real numbers, e.g. when pivoting or
+/// sorting by comparison. Methods shared with [Scalar], such as `sqrt`, then have to be called
+/// as `Scalar::sqrt(x)` or `Float::sqrt(x)`.
+///
+/// ```
+/// use cauchy::*;
+/// use num_traits::float::TotalOrder;
+///
+/// fn pivot<A: RealScalar>(xs: &[A]) -> Option<usize> {
+///     (0..xs.len()).max_by(|&i, &j| Scalar::abs(xs[i]).total_cmp(&Scalar::abs(xs[j])))
+/// }
+/// assert_eq!(pivot(&[1.0, -3.0, 2.0]), Some(1));
+/// ```
+pub trait RealScalar: Scalar<Real = Self> + Float + TotalOrder {}
+
+/// Complex scalars, `c32` and `c64`
+pub trait ComplexScalar: Scalar<Complex = Self> {
+    /// Principal argument in `[-pi, pi]`
+    fn arg(self) -> Self::Real;
+}
+
+/// Scalars which also implement [ComplexFloat] of num-complex with the same real type,
+/// that is `f32`, `f64`, `c32` and `c64`
+///
+/// [ComplexFloat] is sealed and has its own `Real` type, so it cannot be a supertrait of
+/// [Scalar] without excluding newtypes. Bound generic code by this trait instead to pass
+/// values on to code written against either trait. Methods of both traits, such as `abs`
+/// and `powf`, agree for these types, and are called as `Scalar::abs(x)` or
+/// `ComplexFloat::abs(x)` to resolve the name clash.
+///
+/// ```
+/// use cauchy::*;
+/// use num_complex::ComplexFloat;
+///
+/// fn norm<A: ComplexFloat>(x: A) -> A::Real {
+///     x.abs()
+/// }
+///
+/// fn bridge<A: ComplexFloatScalar>(x: A) -> <A as Scalar>::Real {
+///     assert_eq!(Scalar::abs(x), ComplexFloat::abs(x));
+///     norm(x)
+/// }
+/// assert_eq!(bridge(c64::new(3.0, 4.0)), 5.0);
+/// assert_eq!(bridge(-2.0_f32), 2.0);
+/// ```
+pub trait ComplexFloatScalar: Scalar + ComplexFloat<Real = <Self as Scalar>::Real> {}
+
+impl<A: Scalar + ComplexFloat<Real = <A as Scalar>::Real>> ComplexFloatScalar for A {}
+
+macro_rules! impl_slice_cast {
+    ($real:ty) => {
+        // SAFETY: `Complex<T>` is `repr(C)` with two fields of `T`,
+        // so that it has the size of `[T; 2]` and the alignment of `T`
+        fn complex_as_real_slice(xs: &[Complex<$real>]) -> &[$real] {
+            unsafe { core::slice::from_raw_parts(xs.as_ptr() as *const $real, 2 * xs.len()) }
+        }
+        fn complex_as_real_slice_mut(xs: &mut [Complex<$real>]) -> &mut [$real] {
+            unsafe { core::slice::from_raw_parts_mut(xs.as_mut_ptr() as *mut $real, 2 * xs.len()) }
+        }
+        fn real_as_complex_slice(xs: &[$real]) -> Option<&[Complex<$real>]> {
+            if xs.len() % 2 != 0 {
+                return None;
+            }
+            let ptr = xs.as_ptr() as *const Complex<$real>;
+            Some(unsafe { core::slice::from_raw_parts(ptr, xs.len() / 2) })
+        }
+        fn real_as_complex_slice_mut(xs: &mut [$real]) -> Option<&mut [Complex<$real>]> {
+            if xs.len() % 2 != 0 {
+                return None;
+            }
+            let ptr = xs.as_mut_ptr() as *mut Complex<$real>;
+            Some(unsafe { core::slice::from_raw_parts_mut(ptr, xs.len() / 2) })
+        }
+    };
 }
 
 macro_rules! impl_float {
@@ -166,10 +1032,36 @@ macro_rules! impl_with_complex {
 }
 
 macro_rules! impl_scalar {
-    ($real:ty, $complex:ty) => {
+    ($real:ident, $complex:ty, $bits:ty, $wide_real:ty, $wide_complex:ty) => {
         impl Scalar for $real {
             type Real = $real;
             type Complex = $complex;
+            type Widened = $wide_real;
+
+            const IS_COMPLEX: bool = false;
+            const COMPONENTS: usize = 1;
+            const I: Self::Complex = Complex::new(0.0, 1.0);
+            const PI: Self::Real = core::$real::consts::PI;
+            const E: Self::Real = core::$real::consts::E;
+            const EPSILON: Self::Real = <$real>::EPSILON;
+
+            #[inline]
+            fn widen(self) -> Self::Widened {
+                self as $wide_real
+            }
+            #[inline]
+            fn narrow(w: Self::Widened) -> Self {
+                w as $real
+            }
+
+            #[inline]
+            fn frexp(self) -> (Self, i32) {
+                libm::Libm::<$real>::frexp(self)
+            }
+            #[inline]
+            fn ldexp(self, exp: i32) -> Self {
+                libm::Libm::<$real>::ldexp(self, exp)
+            }
 
             #[inline]
             fn re(&self) -> Self::Real {
@@ -185,8 +1077,57 @@ macro_rules! impl_scalar {
                 re
             }
 
+            fn lerp(self, other: Self, t: Self::Real) -> Self {
+                // following C++'s std::lerp
+                if (self <= 0.0 && other >= 0.0) || (self >= 0.0 && other <= 0.0) {
+                    return t * other + (1.0 - t) * self;
+                }
+                if t == 1.0 {
+                    return other;
+                }
+                let x = self + t * (other - self);
+                if (t > 1.0) == (other > self) {
+                    Float::max(other, x)
+                } else {
+                    Float::min(other, x)
+                }
+            }
+            fn midpoint(self, other: Self) -> Self {
+                const LO: $real = <$real>::MIN_POSITIVE * 2.0;
+                const HI: $real = <$real>::MAX / 2.0;
+                let (abs_a, abs_b) = (Float::abs(self), Float::abs(other));
+                if abs_a <= HI && abs_b <= HI {
+                    (self + other) / 2.0
+                } else if abs_a < LO {
+                    self + other / 2.0
+                } else if abs_b < LO {
+                    self / 2.0 + other
+                } else {
+                    self / 2.0 + other / 2.0
+                }
+            }
+
+            #[inline]
+            fn fmod(self, rhs: Self::Real) -> Self {
+                self % rhs
+            }
+            #[inline]
+            fn rem_euclid(self, rhs: Self::Real) -> Self {
+                let r = self % rhs;
+                if r < 0.0 {
+                    r + Float::abs(rhs)
+                } else {
+                    r
+                }
+            }
+            #[inline]
+            fn modf(self) -> (Self, Self) {
+                let (fract, int) = libm::Libm::<$real>::modf(self);
+                (int, fract)
+            }
+
             fn pow(self, n: Self) -> Self {
-                self.powf(n)
+                Float::powf(self, n)
             }
             fn powi(self, n: i32) -> Self {
                 Float::powi(self, n)
@@ -197,6 +1138,13 @@ macro_rules! impl_scalar {
             fn powc(self, n: Self::Complex) -> Self::Complex {
                 self.as_c().powc(n)
             }
+            fn powf_c(self, n: Self::Real) -> Self::Complex {
+                if self >= 0.0 {
+                    Complex::new(Float::powf(self, n), 0.0)
+                } else {
+                    self.as_c().powf(n)
+                }
+            }
 
             #[inline]
             fn real<T: ToPrimitive>(re: T) -> Self::Real {
@@ -210,20 +1158,139 @@ macro_rules! impl_scalar {
                 }
             }
             #[inline]
+            fn try_real<T: ToPrimitive>(re: T) -> Option<Self::Real> {
+                NumCast::from(re)
+            }
+            fn parse(s: &str) -> Result<Self, ParseScalarError> {
+                parse::parse_real_scalar(s)
+            }
+            #[cfg(feature = "std")]
+            fn to_hex_string(&self) -> String {
+                hex::format(*self)
+            }
+            #[cfg(feature = "std")]
+            fn to_shortest_string(&self) -> String {
+                parse::shortest(*self)
+            }
+            fn from_hex_str(s: &str) -> Result<Self, ParseScalarError> {
+                match hex::parse_parts::<$real>(s)? {
+                    (re, im) if im == 0.0 => Ok(re),
+                    _ => Err(ParseScalarError::not_real()),
+                }
+            }
+            #[inline]
+            fn try_from_complex(c: Self::Complex) -> Option<Self> {
+                if c.im == 0.0 {
+                    Some(c.re)
+                } else {
+                    None
+                }
+            }
+            #[inline]
+            fn try_complex<T: ToPrimitive>(re: T, im: T) -> Option<Self::Complex> {
+                Some(Complex {
+                    re: NumCast::from(re)?,
+                    im: NumCast::from(im)?,
+                })
+            }
+            #[inline]
+            fn cis(theta: Self::Real) -> Self::Complex {
+                let (s, c) = Float::sin_cos(theta);
+                Complex::new(c, s)
+            }
+            #[inline]
+            fn i() -> Self::Complex {
+                Complex::new(0.0, 1.0)
+            }
+            #[inline]
+            fn from_parts((re, im): (Self::Real, Self::Real)) -> Self::Complex {
+                Complex::new(re, im)
+            }
+            #[inline]
             fn as_c(&self) -> Self::Complex {
                 Complex::new(*self, 0.0)
             }
             #[inline]
+            fn mul_i(self) -> Self::Complex {
+                Complex::new(0.0, self)
+            }
+            #[inline]
+            fn div_i(self) -> Self::Complex {
+                Complex::new(0.0, -self)
+            }
+            #[inline]
             fn conj(&self) -> Self {
                 *self
             }
             #[inline]
+            fn conj_mul(self, other: Self) -> Self {
+                self * other
+            }
+            #[inline]
+            fn proj(self) -> Self {
+                self
+            }
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Float::mul_add(self, a, b)
+            }
+            #[inline]
             fn square(self) -> Self::Real {
                 self * self
             }
+            #[inline]
+            fn is_normal(&self) -> bool {
+                Float::is_normal(*self)
+            }
+            #[inline]
+            fn is_subnormal(&self) -> bool {
+                Float::classify(*self) == FpCategory::Subnormal
+            }
 
-            fn rand(rng: &mut impl Rng) -> Self {
-                rng.sample(Standard)
+            fn rand_normal(rng: &mut impl Rng) -> Self {
+                rng.sample(StandardNormal)
+            }
+            fn fill_rand(slice: &mut [Self], rng: &mut impl Rng) {
+                // as `Standard`, take the leading mantissa bits scaled into [0, 1)
+                let shift = <$bits>::BITS - <$real>::MANTISSA_DIGITS;
+                let mut bits = [0 as $bits; 64];
+                for chunk in slice.chunks_mut(bits.len()) {
+                    let bits = &mut bits[..chunk.len()];
+                    rng.fill(bits);
+                    for (x, b) in chunk.iter_mut().zip(bits.iter()) {
+                        *x = (b >> shift) as $real * (0.5 * <$real>::EPSILON);
+                    }
+                }
+            }
+            fn rand_cnormal(rng: &mut impl Rng) -> Self {
+                Self::rand_normal(rng)
+            }
+            fn rand_exp(rng: &mut impl Rng, lambda: Self::Real) -> Self {
+                rng.sample(Exp::new(lambda).expect("invalid rate of exponential distribution"))
+            }
+            fn rand_gamma(rng: &mut impl Rng, shape: Self::Real, scale: Self::Real) -> Self {
+                let gamma = Gamma::new(shape, scale);
+                rng.sample(gamma.expect("invalid parameters of gamma distribution"))
+            }
+            fn qrand(seq: &mut Halton) -> Self {
+                seq.next_point().0
+            }
+            fn rand_seeded(seed: u64, index: u64) -> Self {
+                let bits = distributions::splitmix64(seed, index.wrapping_mul(2));
+                (bits >> (64 - <$real>::MANTISSA_DIGITS)) as $real * (0.5 * <$real>::EPSILON)
+            }
+            fn rand_unit(rng: &mut impl Rng) -> Self {
+                if rng.gen() {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            fn rand_disk(rng: &mut impl Rng) -> Self {
+                rng.gen_range(-1.0..=1.0)
+            }
+            fn rand_range(rng: &mut impl Rng, lo: Self::Real, hi: Self::Real) -> Self {
+                rng.gen_range(lo..hi)
             }
 
             impl_with_real!(add_real, +);
@@ -233,7 +1300,10 @@ macro_rules! impl_scalar {
             impl_with_complex!(add_complex, +);
             impl_with_complex!(sub_complex, -);
             impl_with_complex!(mul_complex, *);
-            impl_with_complex!(div_complex, /);
+            #[inline]
+            fn div_complex(self, im: Self::Complex) -> Self::Complex {
+                div::smith(self.as_c(), im)
+            }
 
             impl_float!(sqrt);
             impl_float!(abs);
@@ -251,11 +1321,140 @@ macro_rules! impl_scalar {
             impl_float!(asinh);
             impl_float!(acosh);
             impl_float!(atanh);
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                Float::sin_cos(self)
+            }
+            #[inline]
+            fn to_degrees(self) -> Self {
+                Float::to_degrees(self)
+            }
+            #[inline]
+            fn to_radians(self) -> Self {
+                Float::to_radians(self)
+            }
+            #[inline]
+            fn rsqrt(self) -> Self {
+                arch::Rsqrt::rsqrt(self)
+            }
+            #[inline]
+            fn checked_sqrt(self) -> Option<Self> {
+                if self >= 0.0 {
+                    Some(Float::sqrt(self))
+                } else {
+                    None
+                }
+            }
+            #[inline]
+            fn expi(self) -> Self::Complex {
+                Self::cis(self)
+            }
+            #[inline]
+            fn log(self, base: Self::Real) -> Self {
+                Float::log(self, base)
+            }
+            #[inline]
+            fn erf(self) -> Self {
+                libm::Libm::<$real>::erf(self)
+            }
+            #[inline]
+            fn erfc(self) -> Self {
+                libm::Libm::<$real>::erfc(self)
+            }
+            #[inline]
+            fn gamma(self) -> Self {
+                libm::Libm::<$real>::tgamma(self)
+            }
+            #[inline]
+            fn ln_gamma(self) -> Self {
+                libm::Libm::<$real>::lgamma(self)
+            }
+            fn logaddexp(self, other: Self) -> Self {
+                if Float::is_nan(self) || Float::is_nan(other) {
+                    return self + other;
+                }
+                let (hi, lo) = if self >= other {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                // +inf, or both -inf
+                if Float::is_infinite(hi) {
+                    return hi;
+                }
+                hi + Float::ln_1p(Float::exp(lo - hi))
+            }
+            fn beta(a: Self, b: Self) -> Self {
+                if a > 0.0 && b > 0.0 {
+                    Float::exp(Self::ln_beta(a, b))
+                } else {
+                    Scalar::gamma(a) * Scalar::gamma(b) / Scalar::gamma(a + b)
+                }
+            }
+        }
+
+        impl ScalarLayout for $real {
+            type Bits = $bits;
+            type Bytes = [u8; core::mem::size_of::<$real>()];
+
+            impl_slice_cast!($real);
+            #[inline]
+            fn to_bits(self) -> Self::Bits {
+                <$real>::to_bits(self)
+            }
+            #[inline]
+            fn from_bits(bits: Self::Bits) -> Self {
+                <$real>::from_bits(bits)
+            }
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                <$real>::to_le_bytes(self)
+            }
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                <$real>::to_be_bytes(self)
+            }
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                <$real>::from_le_bytes(bytes)
+            }
+            #[inline]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                <$real>::from_be_bytes(bytes)
+            }
         }
 
         impl Scalar for $complex {
             type Real = $real;
             type Complex = $complex;
+            type Widened = $wide_complex;
+
+            const IS_COMPLEX: bool = true;
+            const COMPONENTS: usize = 2;
+            const I: Self::Complex = Complex::new(0.0, 1.0);
+            const PI: Self::Real = core::$real::consts::PI;
+            const E: Self::Real = core::$real::consts::E;
+            const EPSILON: Self::Real = <$real>::EPSILON;
+
+            #[inline]
+            fn widen(self) -> Self::Widened {
+                Complex::new(self.re as $wide_real, self.im as $wide_real)
+            }
+            #[inline]
+            fn narrow(w: Self::Widened) -> Self {
+                Self::new(w.re as $real, w.im as $real)
+            }
+
+            fn frexp(self) -> (Self, i32) {
+                let larger = Float::max(Float::abs(self.re), Float::abs(self.im));
+                let (_, exp) = Scalar::frexp(larger);
+                (self.ldexp(-exp), exp)
+            }
+            #[inline]
+            fn ldexp(self, exp: i32) -> Self {
+                Self::new(Scalar::ldexp(self.re, exp), Scalar::ldexp(self.im, exp))
+            }
 
             #[inline]
             fn re(&self) -> Self::Real {
@@ -271,11 +1470,61 @@ macro_rules! impl_scalar {
                 Self::new(re, Zero::zero())
             }
 
+            #[inline]
+            fn lerp(self, other: Self, t: Self::Real) -> Self {
+                Self::new(
+                    Scalar::lerp(self.re, other.re, t),
+                    Scalar::lerp(self.im, other.im, t),
+                )
+            }
+            #[inline]
+            fn midpoint(self, other: Self) -> Self {
+                Self::new(
+                    Scalar::midpoint(self.re, other.re),
+                    Scalar::midpoint(self.im, other.im),
+                )
+            }
+
+            #[inline]
+            fn fmod(self, rhs: Self::Real) -> Self {
+                Self::new(self.re % rhs, self.im % rhs)
+            }
+            #[inline]
+            fn rem_euclid(self, rhs: Self::Real) -> Self {
+                Self::new(
+                    Scalar::rem_euclid(self.re, rhs),
+                    Scalar::rem_euclid(self.im, rhs),
+                )
+            }
+            #[inline]
+            fn modf(self) -> (Self, Self) {
+                let (re_int, re_fract) = Scalar::modf(self.re);
+                let (im_int, im_fract) = Scalar::modf(self.im);
+                (Self::new(re_int, im_int), Self::new(re_fract, im_fract))
+            }
+
             fn pow(self, n: Self) -> Self {
                 self.powc(n)
             }
             fn powi(self, n: i32) -> Self {
-                self.powf(n as Self::Real)
+                // binary exponentiation, on the reciprocal for negative exponents
+                let mut base = if n < 0 {
+                    div::smith(Self::new(1.0, 0.0), self)
+                } else {
+                    self
+                };
+                let mut e = n.unsigned_abs();
+                let mut acc = Self::new(1.0, 0.0);
+                while e > 0 {
+                    if e & 1 == 1 {
+                        acc *= base;
+                    }
+                    e >>= 1;
+                    if e > 0 {
+                        base *= base;
+                    }
+                }
+                acc
             }
             fn powf(self, n: Self::Real) -> Self {
                 self.powf(n)
@@ -283,6 +1532,9 @@ macro_rules! impl_scalar {
             fn powc(self, n: Self::Complex) -> Self::Complex {
                 self.powc(n)
             }
+            fn powf_c(self, n: Self::Real) -> Self::Complex {
+                self.powf(n)
+            }
 
             #[inline]
             fn real<T: ToPrimitive>(re: T) -> Self::Real {
@@ -296,24 +1548,165 @@ macro_rules! impl_scalar {
                 }
             }
             #[inline]
+            fn try_real<T: ToPrimitive>(re: T) -> Option<Self::Real> {
+                NumCast::from(re)
+            }
+            fn parse(s: &str) -> Result<Self, ParseScalarError> {
+                let (re, im) = parse::parse_parts(s)?;
+                Ok(Self::new(re, im))
+            }
+            #[cfg(feature = "std")]
+            fn to_hex_string(&self) -> String {
+                let im = hex::format(self.im);
+                let sign = if im.starts_with('-') { "" } else { "+" };
+                format!("{}{}{}i", hex::format(self.re), sign, im)
+            }
+            #[cfg(feature = "std")]
+            fn to_shortest_string(&self) -> String {
+                let im = parse::shortest(self.im);
+                let sign = if im.starts_with('-') { "" } else { "+" };
+                format!("{}{}{}i", parse::shortest(self.re), sign, im)
+            }
+            fn from_hex_str(s: &str) -> Result<Self, ParseScalarError> {
+                let (re, im) = hex::parse_parts(s)?;
+                Ok(Self::new(re, im))
+            }
+            #[inline]
+            fn try_from_complex(c: Self::Complex) -> Option<Self> {
+                Some(c)
+            }
+            #[inline]
+            fn try_complex<T: ToPrimitive>(re: T, im: T) -> Option<Self::Complex> {
+                Some(Complex {
+                    re: NumCast::from(re)?,
+                    im: NumCast::from(im)?,
+                })
+            }
+            #[inline]
+            fn cis(theta: Self::Real) -> Self::Complex {
+                let (s, c) = Float::sin_cos(theta);
+                Complex::new(c, s)
+            }
+            #[inline]
+            fn i() -> Self::Complex {
+                Complex::new(0.0, 1.0)
+            }
+            #[inline]
+            fn from_parts((re, im): (Self::Real, Self::Real)) -> Self::Complex {
+                Complex::new(re, im)
+            }
+            #[inline]
             fn as_c(&self) -> Self::Complex {
                 *self
             }
             #[inline]
+            fn mul_i(self) -> Self::Complex {
+                Complex::new(-self.im, self.re)
+            }
+            #[inline]
+            fn div_i(self) -> Self::Complex {
+                Complex::new(self.im, -self.re)
+            }
+            #[inline]
             fn conj(&self) -> Self {
                 Complex::conj(self)
             }
             #[inline]
+            fn proj(self) -> Self {
+                if self.re.is_infinite() || self.im.is_infinite() {
+                    Self::new(<$real>::infinity(), Float::copysign(0.0, self.im))
+                } else {
+                    self
+                }
+            }
+            #[inline]
+            fn conj_mul(self, other: Self) -> Self {
+                // (a - ib)(c + id) = (ac + bd) + i(ad - bc)
+                Self::new(
+                    self.re * other.re + self.im * other.im,
+                    self.re * other.im - self.im * other.re,
+                )
+            }
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                let re = Float::mul_add(self.re, a.re, Float::mul_add(-self.im, a.im, b.re));
+                let im = Float::mul_add(self.re, a.im, Float::mul_add(self.im, a.re, b.im));
+                Self::new(re, im)
+            }
+            #[inline]
             fn square(self) -> Self::Real {
                 Complex::norm_sqr(&self)
             }
             #[inline]
             fn abs(self) -> Self::Real {
-                Complex::norm(self)
+                // hypot scales internally, unlike sqrt(norm_sqr)
+                Float::hypot(self.re, self.im)
+            }
+            #[inline]
+            fn is_normal(&self) -> bool {
+                Float::is_normal(self.re) || Float::is_normal(self.im)
+            }
+            #[inline]
+            fn is_subnormal(&self) -> bool {
+                Scalar::is_subnormal(&self.re) || Scalar::is_subnormal(&self.im)
             }
 
-            fn rand(rng: &mut impl Rng) -> Self {
-                rng.sample(Standard)
+            fn rand_normal(rng: &mut impl Rng) -> Self {
+                Self::new(rng.sample(StandardNormal), rng.sample(StandardNormal))
+            }
+            fn fill_rand(slice: &mut [Self], rng: &mut impl Rng) {
+                let shift = <$bits>::BITS - <$real>::MANTISSA_DIGITS;
+                let mut bits = [0 as $bits; 64];
+                for chunk in slice.chunks_mut(bits.len() / 2) {
+                    let bits = &mut bits[..2 * chunk.len()];
+                    rng.fill(bits);
+                    for (z, b) in chunk.iter_mut().zip(bits.chunks_exact(2)) {
+                        let scale = 0.5 * <$real>::EPSILON;
+                        *z = Self::new(
+                            (b[0] >> shift) as $real * scale,
+                            (b[1] >> shift) as $real * scale,
+                        );
+                    }
+                }
+            }
+            fn qrand(seq: &mut Halton) -> Self {
+                let (re, im) = seq.next_point();
+                Self::new(re, im)
+            }
+            fn rand_seeded(seed: u64, index: u64) -> Self {
+                let bits = distributions::splitmix64(seed, index.wrapping_mul(2).wrapping_add(1));
+                let im = (bits >> (64 - <$real>::MANTISSA_DIGITS)) as $real;
+                Self::new(<$real>::rand_seeded(seed, index), im * (0.5 * <$real>::EPSILON))
+            }
+            fn rand_cnormal(rng: &mut impl Rng) -> Self {
+                Self::rand_normal(rng).mul_real(<$real as FloatConst>::FRAC_1_SQRT_2())
+            }
+            fn rand_exp(rng: &mut impl Rng, lambda: Self::Real) -> Self {
+                let r = <$real>::rand_exp(rng, lambda);
+                Self::rand_unit(rng).mul_real(r)
+            }
+            fn rand_gamma(rng: &mut impl Rng, shape: Self::Real, scale: Self::Real) -> Self {
+                let r = <$real>::rand_gamma(rng, shape, scale);
+                Self::rand_unit(rng).mul_real(r)
+            }
+            fn rand_unit(rng: &mut impl Rng) -> Self {
+                Self::cis(rng.gen::<$real>() * <$real as FloatConst>::TAU())
+            }
+            fn rand_disk(rng: &mut impl Rng) -> Self {
+                // rejection from the enclosing square accepts with probability pi/4
+                loop {
+                    let z = Self::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+                    if z.norm_sqr() <= 1.0 {
+                        return z;
+                    }
+                }
+            }
+            fn rand_range(rng: &mut impl Rng, lo: Self::Real, hi: Self::Real) -> Self {
+                assert!(0.0 <= lo && lo < hi, "invalid annulus {} <= |z| < {}", lo, hi);
+                // the area below radius r grows as r^2
+                let (lo2, hi2) = (lo * lo, hi * hi);
+                let r = Float::sqrt(lo2 + rng.gen::<$real>() * (hi2 - lo2));
+                let theta = rng.gen::<$real>() * <$real as FloatConst>::TAU();
+                Self::from_polar(r, theta)
             }
 
             impl_with_real!(add_real, +);
@@ -323,7 +1716,21 @@ macro_rules! impl_scalar {
             impl_with_complex!(add_complex, +);
             impl_with_complex!(sub_complex, -);
             impl_with_complex!(mul_complex, *);
-            impl_with_complex!(div_complex, /);
+            #[inline]
+            fn div_complex(self, im: Self::Complex) -> Self::Complex {
+                div::smith(self, im)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() {
+                    return None;
+                }
+                let q = div::smith(self, rhs);
+                if q.re.is_nan() || q.im.is_nan() {
+                    None
+                } else {
+                    Some(q)
+                }
+            }
 
             impl_complex!(sqrt);
             impl_complex!(exp);
@@ -340,9 +1747,151 @@ macro_rules! impl_scalar {
             impl_complex!(asinh);
             impl_complex!(acosh);
             impl_complex!(atanh);
+
+            #[inline]
+            fn checked_sqrt(self) -> Option<Self> {
+                Some(Complex::sqrt(self))
+            }
+            fn rsqrt(self) -> Self {
+                if self.is_zero() {
+                    return Self::new(<$real>::infinity(), 0.0);
+                }
+                // 1 / s = conj(s) / |s|^2, scaled in two steps to avoid overflow
+                let s = Complex::sqrt(self);
+                let r = s.norm();
+                s.conj().unscale(r).unscale(r)
+            }
+            #[inline]
+            fn to_degrees(self) -> Self {
+                Self::new(Float::to_degrees(self.re), Float::to_degrees(self.im))
+            }
+            #[inline]
+            fn to_radians(self) -> Self {
+                Self::new(Float::to_radians(self.re), Float::to_radians(self.im))
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                // sin(a + ib) = sin(a) cosh(b) + i cos(a) sinh(b)
+                // cos(a + ib) = cos(a) cosh(b) - i sin(a) sinh(b)
+                let (s, c) = Float::sin_cos(self.re);
+                let (sh, ch) = (Float::sinh(self.im), Float::cosh(self.im));
+                (Self::new(s * ch, c * sh), Self::new(c * ch, -s * sh))
+            }
+            #[inline]
+            fn expi(self) -> Self::Complex {
+                // exp(i (a + ib)) = exp(-b) (cos(a) + i sin(a))
+                Self::cis(self.re) * Float::exp(-self.im)
+            }
+            #[inline]
+            fn log(self, base: Self::Real) -> Self {
+                // principal branch: ln|z| / ln(base) + i arg(z) / ln(base)
+                Complex::log(self, base)
+            }
+            fn erf(self) -> Self {
+                if self.im == 0.0 {
+                    Self::new(libm::Libm::<$real>::erf(self.re), self.im)
+                } else {
+                    special::erf(self)
+                }
+            }
+            fn erfc(self) -> Self {
+                if self.im == 0.0 {
+                    Self::new(libm::Libm::<$real>::erfc(self.re), -self.im)
+                } else {
+                    special::erfc(self)
+                }
+            }
+            fn gamma(self) -> Self {
+                if self.im == 0.0 {
+                    Self::new(libm::Libm::<$real>::tgamma(self.re), self.im)
+                } else {
+                    special::gamma(self)
+                }
+            }
+            fn ln_gamma(self) -> Self {
+                if self.im == 0.0 && self.re > 0.0 {
+                    Self::new(libm::Libm::<$real>::lgamma(self.re), self.im)
+                } else {
+                    special::ln_gamma(self)
+                }
+            }
+            #[inline]
+            fn beta(a: Self, b: Self) -> Self {
+                Self::ln_beta(a, b).exp()
+            }
+            fn logaddexp(self, other: Self) -> Self {
+                let (hi, lo) = if self.re >= other.re {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                if Float::is_infinite(hi.re) {
+                    return hi;
+                }
+                hi + special::ln_1p((lo - hi).exp())
+            }
+        }
+
+        impl ScalarLayout for $complex {
+            type Bits = ($bits, $bits);
+            type Bytes = [u8; 2 * core::mem::size_of::<$real>()];
+
+            impl_slice_cast!($real);
+            #[inline]
+            fn to_bits(self) -> Self::Bits {
+                (self.re.to_bits(), self.im.to_bits())
+            }
+            #[inline]
+            fn from_bits((re, im): Self::Bits) -> Self {
+                Self::new(<$real>::from_bits(re), <$real>::from_bits(im))
+            }
+            fn to_le_bytes(self) -> Self::Bytes {
+                let mut bytes = Self::Bytes::default();
+                let (re, im) = bytes.split_at_mut(core::mem::size_of::<$real>());
+                re.copy_from_slice(&self.re.to_le_bytes());
+                im.copy_from_slice(&self.im.to_le_bytes());
+                bytes
+            }
+            fn to_be_bytes(self) -> Self::Bytes {
+                let mut bytes = Self::Bytes::default();
+                let (re, im) = bytes.split_at_mut(core::mem::size_of::<$real>());
+                re.copy_from_slice(&self.re.to_be_bytes());
+                im.copy_from_slice(&self.im.to_be_bytes());
+                bytes
+            }
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                let (re, im) = bytes.split_at(core::mem::size_of::<$real>());
+                Self::new(
+                    <$real>::from_le_bytes(re.try_into().unwrap()),
+                    <$real>::from_le_bytes(im.try_into().unwrap()),
+                )
+            }
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                let (re, im) = bytes.split_at(core::mem::size_of::<$real>());
+                Self::new(
+                    <$real>::from_be_bytes(re.try_into().unwrap()),
+                    <$real>::from_be_bytes(im.try_into().unwrap()),
+                )
+            }
         }
     }
 }
 
-impl_scalar!(f32, c32);
-impl_scalar!(f64, c64);
+impl_scalar!(f32, c32, u32, f64, c64);
+impl_scalar!(f64, c64, u64, f64, c64);
+
+impl RealScalar for f32 {}
+impl RealScalar for f64 {}
+
+impl ComplexScalar for c32 {
+    #[inline]
+    fn arg(self) -> f32 {
+        Complex::arg(self)
+    }
+}
+
+impl ComplexScalar for c64 {
+    #[inline]
+    fn arg(self) -> f64 {
+        Complex::arg(self)
+    }
+}
@@ -0,0 +1,152 @@
+//! Arithmetic between scalars of different precisions
+
+use crate::{c32, c64, div, Scalar};
+use num_complex::Complex;
+
+/// Exact conversion into a type of at least the same precision
+trait Promote<T> {
+    fn promote(self) -> T;
+}
+
+macro_rules! impl_promote {
+    ($from:ty => $to:ty, |$x:ident| $e:expr) => {
+        impl Promote<$to> for $from {
+            #[inline]
+            fn promote(self) -> $to {
+                let $x = self;
+                $e
+            }
+        }
+    };
+}
+
+impl_promote!(f32 => f32, |x| x);
+impl_promote!(f32 => f64, |x| x as f64);
+impl_promote!(f32 => c32, |x| Complex::new(x, 0.0));
+impl_promote!(f32 => c64, |x| Complex::new(x as f64, 0.0));
+impl_promote!(f64 => f64, |x| x);
+impl_promote!(f64 => c64, |x| Complex::new(x, 0.0));
+impl_promote!(c32 => c32, |x| x);
+impl_promote!(c32 => c64, |x| Complex::new(x.re as f64, x.im as f64));
+impl_promote!(c64 => c64, |x| x);
+
+/// Binary operations with a scalar of another precision, evaluated in [MixedOps::Promoted],
+/// the type of the larger precision which is complex if either operand is
+///
+/// The operands are converted without rounding, and real operands of complex operations
+/// act on each component as [Scalar::mul_real] instead of being promoted to complex numbers.
+/// Complex quotients avoid intermediate overflow as [Scalar::div_complex].
+///
+/// ```
+/// use cauchy::*;
+///
+/// // low-precision matrix times high-precision vector
+/// fn gemv<A: MixedOps<B>, B: Scalar>(a: &[A], x: &[B], n: usize) -> Vec<A::Promoted> {
+///     a.chunks(n)
+///         .map(|row| row.iter().zip(x).map(|(&a, &x)| a.mul_mixed(x)).sum())
+///         .collect()
+/// }
+///
+/// let a = [1.0_f32, 2.0, 3.0, 4.0];
+/// let x = [c64::new(0.1, 1.0), c64::new(0.2, 0.0)];
+/// assert_eq!(gemv(&a, &x, 2), vec![
+///     c64::new(0.1 + 2.0 * 0.2, 1.0),
+///     c64::new(3.0 * 0.1 + 4.0 * 0.2, 3.0),
+/// ]);
+/// assert_eq!(0.1_f32.add_mixed(0.2_f64), 0.1_f32 as f64 + 0.2);
+///
+/// // no overflow of |z|^2 for large exponents
+/// let z = c64::new(1e200, 1e200);
+/// assert_eq!(1.0_f64.div_mixed(z), 1.0_f64.div_complex(z));
+/// assert_eq!(c32::new(1.0, 0.0).div_mixed(z), c64::new(5e-201, -5e-201));
+/// ```
+pub trait MixedOps<Rhs: Scalar>: Scalar {
+    type Promoted: Scalar;
+    /// Sum `self + rhs`
+    fn add_mixed(self, rhs: Rhs) -> Self::Promoted;
+    /// Difference `self - rhs`
+    fn sub_mixed(self, rhs: Rhs) -> Self::Promoted;
+    /// Product `self * rhs`
+    fn mul_mixed(self, rhs: Rhs) -> Self::Promoted;
+    /// Quotient `self / rhs`
+    fn div_mixed(self, rhs: Rhs) -> Self::Promoted;
+}
+
+macro_rules! impl_mixed {
+    ($lhs:ty, $rhs:ty => $out:ty, $($op:ident, $method:ident, $tt:tt);*) => {
+        impl MixedOps<$rhs> for $lhs {
+            type Promoted = $out;
+            $(
+                #[inline]
+                fn $method(self, rhs: $rhs) -> $out {
+                    impl_mixed!(@$op $out, self, rhs, $tt)
+                }
+            )*
+        }
+    };
+    // both real or both complex
+    (@same $out:ty, $a:ident, $b:ident, $op:tt) => {
+        Promote::<$out>::promote($a) $op Promote::<$out>::promote($b)
+    };
+    // both complex, dividing without intermediate overflow as Scalar::div_complex
+    (@smith $out:ty, $a:ident, $b:ident, $op:tt) => {
+        div::smith(Promote::<$out>::promote($a), Promote::<$out>::promote($b))
+    };
+    // complex and real, component-wise not to mix infinities with the zero imaginary part
+    (@complex_real $out:ty, $a:ident, $b:ident, $method:ident) => {
+        Promote::<$out>::promote($a).$method(Promote::<<$out as Scalar>::Real>::promote($b))
+    };
+    ($lhs:ty, $rhs:ty => $out:ty) => {
+        impl_mixed!($lhs, $rhs => $out,
+            same, add_mixed, +; same, sub_mixed, -; same, mul_mixed, *; same, div_mixed, /);
+    };
+    ($lhs:ty, $rhs:ty => $out:ty, complex) => {
+        impl_mixed!($lhs, $rhs => $out,
+            same, add_mixed, +; same, sub_mixed, -; same, mul_mixed, *; smith, div_mixed, /);
+    };
+    ($lhs:ty, $rhs:ty => $out:ty, complex_real) => {
+        impl_mixed!($lhs, $rhs => $out,
+            complex_real, add_mixed, add_real;
+            complex_real, sub_mixed, sub_real;
+            complex_real, mul_mixed, mul_real;
+            complex_real, div_mixed, div_real);
+    };
+    ($lhs:ty, $rhs:ty => $out:ty, real_complex) => {
+        impl MixedOps<$rhs> for $lhs {
+            type Promoted = $out;
+            #[inline]
+            fn add_mixed(self, rhs: $rhs) -> $out {
+                rhs.add_mixed(self)
+            }
+            #[inline]
+            fn sub_mixed(self, rhs: $rhs) -> $out {
+                (-rhs).add_mixed(self)
+            }
+            #[inline]
+            fn mul_mixed(self, rhs: $rhs) -> $out {
+                rhs.mul_mixed(self)
+            }
+            #[inline]
+            fn div_mixed(self, rhs: $rhs) -> $out {
+                div::smith(Promote::<$out>::promote(self), Promote::<$out>::promote(rhs))
+            }
+        }
+    };
+}
+
+impl_mixed!(f32, f32 => f32);
+impl_mixed!(f32, f64 => f64);
+impl_mixed!(f64, f32 => f64);
+impl_mixed!(f64, f64 => f64);
+impl_mixed!(c32, c32 => c32, complex);
+impl_mixed!(c32, c64 => c64, complex);
+impl_mixed!(c64, c32 => c64, complex);
+impl_mixed!(c64, c64 => c64, complex);
+impl_mixed!(c32, f32 => c32, complex_real);
+impl_mixed!(c32, f64 => c64, complex_real);
+impl_mixed!(c64, f32 => c64, complex_real);
+impl_mixed!(c64, f64 => c64, complex_real);
+impl_mixed!(f32, c32 => c32, real_complex);
+impl_mixed!(f32, c64 => c64, real_complex);
+impl_mixed!(f64, c32 => c64, real_complex);
+impl_mixed!(f64, c64 => c64, real_complex);
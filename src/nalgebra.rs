@@ -0,0 +1,524 @@
+//! Bridge to the `ComplexField` and `RealField` traits of [nalgebra](https://docs.rs/nalgebra)
+//!
+//! `f32`, `f64`, `c32` and `c64` implement both [Scalar] and `ComplexField`, with the same real
+//! type. [NalgebraScalar] and [NalgebraRealScalar] name this combination, so that a kernel
+//! bounded by them can use [Scalar] methods as well as nalgebra matrices, and conversely pass
+//! its scalars to kernels generic over `ComplexField`.
+//! Both traits define methods like `abs` and `sqrt`, which have to be called fully qualified
+//! as `Scalar::abs(x)` or `ComplexField::abs(x)` if both are in scope.
+//!
+//! Other scalars, such as newtypes, are passed to nalgebra wrapped in [NalgebraAdapter].
+//!
+//! ```
+//! use cauchy::{c64, nalgebra::NalgebraScalar, Scalar};
+//! use nalgebra::DVector;
+//!
+//! fn rms<A: NalgebraScalar>(x: &DVector<A>) -> A::Real {
+//!     Scalar::sqrt(x.norm_squared() / <A as Scalar>::real(x.len()))
+//! }
+//! let x = DVector::from_vec(vec![c64::new(3.0, 4.0), c64::new(0.0, 5.0)]);
+//! assert_eq!(rms(&x), 5.0);
+//! ```
+
+use crate::{special, sum::from_components, ParseScalarError, RealScalar, Scalar};
+use ::nalgebra::{ComplexField, Field, RealField, SimdValue};
+use ::simba::scalar::SubsetOf;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::f64::consts;
+use core::fmt::{self, Display};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use num_traits::{Float, FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+
+/// [Scalar] which is also a `ComplexField` of nalgebra with the same real type
+pub trait NalgebraScalar:
+    Scalar<Real = <Self as NalgebraScalar>::RealField>
+    + ComplexField<RealField = <Self as NalgebraScalar>::RealField>
+{
+    /// Real type shared by [Scalar] and `ComplexField`
+    type RealField: NalgebraRealScalar;
+}
+
+impl<A> NalgebraScalar for A
+where
+    A: Scalar + ComplexField<RealField = <A as Scalar>::Real>,
+    A::Real: NalgebraRealScalar,
+{
+    type RealField = A::Real;
+}
+
+/// [RealScalar] which is also a `RealField` of nalgebra
+pub trait NalgebraRealScalar: RealScalar + RealField {}
+
+impl<R: RealScalar + RealField> NalgebraRealScalar for R {}
+
+/// Newtype implementing `ComplexField`, and `RealField` for real types, of nalgebra for any
+/// [Scalar], e.g. the newtypes of [impl_scalar_newtype](crate::impl_scalar_newtype)
+///
+/// The methods of nalgebra are computed by those of [Scalar], and component-wise for rounding
+/// functions like `floor` as nalgebra does for its complex numbers.
+///
+/// ```
+/// use cauchy::{c64, nalgebra::NalgebraAdapter as N};
+/// use nalgebra::Matrix2;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Volt(c64);
+/// cauchy::impl_scalar_newtype!(Volt, c64);
+///
+/// let v = |re, im| N(Volt(c64::new(re, im)));
+/// let m = Matrix2::new(v(1.0, 1.0), v(0.0, 2.0), v(0.0, 0.0), v(2.0, 0.0));
+/// let inv = m.try_inverse().unwrap();
+/// assert_eq!(inv[(0, 0)], v(0.5, -0.5));
+/// assert_eq!(m * inv, Matrix2::identity());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct NalgebraAdapter<A>(pub A);
+
+impl<A: Display> Display for NalgebraAdapter<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<A: Scalar> Neg for NalgebraAdapter<A> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        NalgebraAdapter(-self.0)
+    }
+}
+
+macro_rules! impl_adapter_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<A: Scalar> $op for NalgebraAdapter<A> {
+            type Output = Self;
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                NalgebraAdapter($op::$method(self.0, rhs.0))
+            }
+        }
+        impl<A: Scalar> $op_assign for NalgebraAdapter<A> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                $op_assign::$method_assign(&mut self.0, rhs.0)
+            }
+        }
+    };
+}
+
+impl_adapter_op!(Add, add, AddAssign, add_assign);
+impl_adapter_op!(Sub, sub, SubAssign, sub_assign);
+impl_adapter_op!(Mul, mul, MulAssign, mul_assign);
+impl_adapter_op!(Div, div, DivAssign, div_assign);
+impl_adapter_op!(Rem, rem, RemAssign, rem_assign);
+
+impl<A: Scalar> Zero for NalgebraAdapter<A> {
+    #[inline]
+    fn zero() -> Self {
+        NalgebraAdapter(A::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<A: Scalar> One for NalgebraAdapter<A> {
+    #[inline]
+    fn one() -> Self {
+        NalgebraAdapter(A::one())
+    }
+}
+
+impl<A: Scalar> Num for NalgebraAdapter<A> {
+    type FromStrRadixErr = ParseScalarError;
+    /// Parse as [Scalar::parse] for radix 10 and as [Scalar::from_hex_str] for radix 16
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseScalarError> {
+        match radix {
+            10 => A::parse(s).map(NalgebraAdapter),
+            16 => A::from_hex_str(s).map(NalgebraAdapter),
+            _ => Err(ParseScalarError::invalid()),
+        }
+    }
+}
+
+impl<A: Scalar> FromPrimitive for NalgebraAdapter<A> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        A::from_i64(n).map(NalgebraAdapter)
+    }
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        A::from_u64(n).map(NalgebraAdapter)
+    }
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        A::from_f64(n).map(NalgebraAdapter)
+    }
+}
+
+impl<R: RealScalar> Signed for NalgebraAdapter<R> {
+    #[inline]
+    fn abs(&self) -> Self {
+        NalgebraAdapter(Float::abs(self.0))
+    }
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.0 <= other.0 {
+            Self::zero()
+        } else {
+            NalgebraAdapter(self.0 - other.0)
+        }
+    }
+    #[inline]
+    fn signum(&self) -> Self {
+        NalgebraAdapter(Float::signum(self.0))
+    }
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.0 > R::zero()
+    }
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.0 < R::zero()
+    }
+}
+
+/// Real types with the comparisons of approx required by `RealField`, that is `f32` and `f64`
+pub trait ApproxReal: RealScalar + RelativeEq<Epsilon = Self> + UlpsEq<Epsilon = Self> {}
+
+impl<R: RealScalar + RelativeEq<Epsilon = R> + UlpsEq<Epsilon = R>> ApproxReal for R {}
+
+impl<R: ApproxReal> AbsDiffEq for NalgebraAdapter<R> {
+    type Epsilon = Self;
+    fn default_epsilon() -> Self {
+        NalgebraAdapter(R::default_epsilon())
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon.0)
+    }
+}
+
+impl<R: ApproxReal> RelativeEq for NalgebraAdapter<R> {
+    fn default_max_relative() -> Self {
+        NalgebraAdapter(R::default_max_relative())
+    }
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+        self.0.relative_eq(&other.0, epsilon.0, max_relative.0)
+    }
+}
+
+impl<R: ApproxReal> UlpsEq for NalgebraAdapter<R> {
+    fn default_max_ulps() -> u32 {
+        R::default_max_ulps()
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: Self, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon.0, max_ulps)
+    }
+}
+
+impl<A: Scalar> SimdValue for NalgebraAdapter<A> {
+    const LANES: usize = 1;
+    type Element = Self;
+    type SimdBool = bool;
+    #[inline]
+    fn splat(val: Self) -> Self {
+        val
+    }
+    #[inline]
+    fn extract(&self, _: usize) -> Self {
+        *self
+    }
+    #[inline]
+    unsafe fn extract_unchecked(&self, _: usize) -> Self {
+        *self
+    }
+    #[inline]
+    fn replace(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+    #[inline]
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+    #[inline]
+    fn select(self, cond: bool, other: Self) -> Self {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<A: Scalar> Field for NalgebraAdapter<A> {}
+
+impl<A: Scalar> SubsetOf<NalgebraAdapter<A>> for NalgebraAdapter<A> {
+    #[inline]
+    fn to_superset(&self) -> Self {
+        *self
+    }
+    #[inline]
+    fn from_superset_unchecked(element: &Self) -> Self {
+        *element
+    }
+    #[inline]
+    fn is_in_subset(_: &Self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_subset {
+    ($real:ty) => {
+        impl<A: Scalar> SubsetOf<NalgebraAdapter<A>> for $real {
+            #[inline]
+            fn to_superset(&self) -> NalgebraAdapter<A> {
+                NalgebraAdapter(A::from_real(A::real(*self)))
+            }
+            #[inline]
+            fn from_superset_unchecked(element: &NalgebraAdapter<A>) -> Self {
+                element.0.re().to_f64().unwrap() as $real
+            }
+            #[inline]
+            fn is_in_subset(element: &NalgebraAdapter<A>) -> bool {
+                element.0.im().is_zero()
+            }
+        }
+    };
+}
+
+impl_subset!(f32);
+impl_subset!(f64);
+
+impl<A: Scalar> NalgebraAdapter<A> {
+    /// Apply `f` to both components
+    fn map_parts(self, f: impl Fn(A::Real) -> A::Real) -> Self {
+        NalgebraAdapter(from_components(f(self.0.re()), f(self.0.im())))
+    }
+
+    /// Apply the real function `f` to real numbers, and `g` otherwise
+    fn real_or(self, f: impl Fn(A::Real) -> A::Real, g: impl Fn(A) -> A) -> Self {
+        NalgebraAdapter(if A::IS_COMPLEX {
+            g(self.0)
+        } else {
+            A::from_real(f(self.0.re()))
+        })
+    }
+}
+
+macro_rules! delegate {
+    ($($method:ident),*) => {
+        $(
+            #[inline]
+            fn $method(self) -> Self {
+                NalgebraAdapter(Scalar::$method(self.0))
+            }
+        )*
+    };
+}
+
+impl<A> ComplexField for NalgebraAdapter<A>
+where
+    A: Scalar + Display,
+    A::Real: ApproxReal + Display,
+{
+    type RealField = NalgebraAdapter<A::Real>;
+
+    #[inline]
+    fn from_real(re: Self::RealField) -> Self {
+        NalgebraAdapter(A::from_real(re.0))
+    }
+    #[inline]
+    fn real(self) -> Self::RealField {
+        NalgebraAdapter(self.0.re())
+    }
+    #[inline]
+    fn imaginary(self) -> Self::RealField {
+        NalgebraAdapter(self.0.im())
+    }
+    #[inline]
+    fn modulus(self) -> Self::RealField {
+        NalgebraAdapter(Scalar::abs(self.0))
+    }
+    #[inline]
+    fn modulus_squared(self) -> Self::RealField {
+        NalgebraAdapter(self.0.square())
+    }
+    #[inline]
+    fn argument(self) -> Self::RealField {
+        NalgebraAdapter(Float::atan2(self.0.im(), self.0.re()))
+    }
+    #[inline]
+    fn norm1(self) -> Self::RealField {
+        NalgebraAdapter(Float::abs(self.0.re()) + Float::abs(self.0.im()))
+    }
+    #[inline]
+    fn scale(self, factor: Self::RealField) -> Self {
+        NalgebraAdapter(self.0.mul_real(factor.0))
+    }
+    #[inline]
+    fn unscale(self, factor: Self::RealField) -> Self {
+        NalgebraAdapter(self.0.div_real(factor.0))
+    }
+    fn floor(self) -> Self {
+        self.map_parts(Float::floor)
+    }
+    fn ceil(self) -> Self {
+        self.map_parts(Float::ceil)
+    }
+    fn round(self) -> Self {
+        self.map_parts(Float::round)
+    }
+    fn trunc(self) -> Self {
+        self.map_parts(Float::trunc)
+    }
+    fn fract(self) -> Self {
+        self.map_parts(Float::fract)
+    }
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        NalgebraAdapter(Scalar::mul_add(self.0, a.0, b.0))
+    }
+    #[inline]
+    fn abs(self) -> Self::RealField {
+        NalgebraAdapter(Scalar::abs(self.0))
+    }
+    #[inline]
+    fn hypot(self, other: Self) -> Self::RealField {
+        NalgebraAdapter(Float::hypot(Scalar::abs(self.0), Scalar::abs(other.0)))
+    }
+    #[inline]
+    fn recip(self) -> Self {
+        NalgebraAdapter(A::one() / self.0)
+    }
+    #[inline]
+    fn conjugate(self) -> Self {
+        NalgebraAdapter(self.0.conj())
+    }
+    delegate!(
+        sin, cos, tan, asin, acos, atan, sinh, cosh, tanh, asinh, acosh, atanh, ln, sqrt, exp
+    );
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = Scalar::sin_cos(self.0);
+        (NalgebraAdapter(s), NalgebraAdapter(c))
+    }
+    #[inline]
+    fn log(self, base: Self::RealField) -> Self {
+        NalgebraAdapter(Scalar::log(self.0, base.0))
+    }
+    fn log2(self) -> Self {
+        self.real_or(Float::log2, |z| z.ln().div_real(Float::ln(A::real(2))))
+    }
+    fn log10(self) -> Self {
+        self.real_or(Float::log10, |z| z.ln().div_real(Float::ln(A::real(10))))
+    }
+    fn ln_1p(self) -> Self {
+        self.real_or(Float::ln_1p, special::ln_1p)
+    }
+    fn exp2(self) -> Self {
+        self.real_or(Float::exp2, |z| z.mul_real(Float::ln(A::real(2))).exp())
+    }
+    fn exp_m1(self) -> Self {
+        // e^x (cos y + i sin y) - 1 = exp_m1(x) cos y - 2 sin^2(y / 2) + i e^x sin y
+        self.real_or(Float::exp_m1, |z| {
+            let (x, y) = (z.re(), z.im());
+            let (s, c) = Float::sin_cos(y);
+            let h = Float::sin(y / A::real(2));
+            let re = Float::exp_m1(x) * c - A::real(2) * h * h;
+            from_components(re, Float::exp(x) * s)
+        })
+    }
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        NalgebraAdapter(Scalar::powi(self.0, n))
+    }
+    #[inline]
+    fn powf(self, n: Self::RealField) -> Self {
+        NalgebraAdapter(Scalar::powf(self.0, n.0))
+    }
+    #[inline]
+    fn powc(self, n: Self) -> Self {
+        NalgebraAdapter(Scalar::pow(self.0, n.0))
+    }
+    fn cbrt(self) -> Self {
+        self.real_or(Float::cbrt, |z| z.powf(A::real(3).recip()))
+    }
+    #[inline]
+    fn is_finite(&self) -> bool {
+        Float::is_finite(self.0.re()) && Float::is_finite(self.0.im())
+    }
+    #[inline]
+    fn try_sqrt(self) -> Option<Self> {
+        self.0.checked_sqrt().map(NalgebraAdapter)
+    }
+}
+
+macro_rules! constants {
+    ($($method:ident = $value:expr),*) => {
+        $(
+            #[inline]
+            fn $method() -> Self {
+                NalgebraAdapter(R::real($value))
+            }
+        )*
+    };
+}
+
+impl<R: ApproxReal + Display> RealField for NalgebraAdapter<R> {
+    #[inline]
+    fn is_sign_positive(&self) -> bool {
+        Float::is_sign_positive(self.0)
+    }
+    #[inline]
+    fn is_sign_negative(&self) -> bool {
+        Float::is_sign_negative(self.0)
+    }
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        NalgebraAdapter(Float::abs(self.0) * Float::signum(sign.0))
+    }
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        NalgebraAdapter(Float::max(self.0, other.0))
+    }
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        NalgebraAdapter(Float::min(self.0, other.0))
+    }
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        NalgebraAdapter(Float::max(Float::min(self.0, max.0), min.0))
+    }
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        NalgebraAdapter(Float::atan2(self.0, other.0))
+    }
+    #[inline]
+    fn min_value() -> Option<Self> {
+        Some(NalgebraAdapter(Float::min_value()))
+    }
+    #[inline]
+    fn max_value() -> Option<Self> {
+        Some(NalgebraAdapter(Float::max_value()))
+    }
+    constants!(
+        pi = consts::PI,
+        two_pi = consts::TAU,
+        frac_pi_2 = consts::FRAC_PI_2,
+        frac_pi_3 = consts::FRAC_PI_3,
+        frac_pi_4 = consts::FRAC_PI_4,
+        frac_pi_6 = consts::FRAC_PI_6,
+        frac_pi_8 = consts::FRAC_PI_8,
+        frac_1_pi = consts::FRAC_1_PI,
+        frac_2_pi = consts::FRAC_2_PI,
+        frac_2_sqrt_pi = consts::FRAC_2_SQRT_PI,
+        e = consts::E,
+        log2_e = consts::LOG2_E,
+        log10_e = consts::LOG10_E,
+        ln_2 = consts::LN_2,
+        ln_10 = consts::LN_10
+    );
+}
@@ -0,0 +1,50 @@
+//! Errors for checked narrowing conversions
+
+use core::fmt;
+
+/// An error which can be returned when converting a scalar by [crate::Scalar::try_narrow]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NarrowError {
+    kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ErrorKind {
+    NotReal,
+    Overflow,
+    Inexact,
+}
+
+impl NarrowError {
+    pub(crate) fn not_real() -> Self {
+        NarrowError {
+            kind: ErrorKind::NotReal,
+        }
+    }
+
+    pub(crate) fn overflow() -> Self {
+        NarrowError {
+            kind: ErrorKind::Overflow,
+        }
+    }
+
+    pub(crate) fn inexact() -> Self {
+        NarrowError {
+            kind: ErrorKind::Inexact,
+        }
+    }
+}
+
+impl fmt::Display for NarrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ErrorKind::NotReal => "non-zero imaginary part for real scalar",
+            ErrorKind::Overflow => "value overflows target precision",
+            ErrorKind::Inexact => "rounding error exceeds tolerance",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NarrowError {}
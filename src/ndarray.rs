@@ -0,0 +1,115 @@
+//! Interoperation with [ndarray](https://docs.rs/ndarray)
+//!
+//! Every [Scalar] of this crate, including the newtypes of
+//! [impl_scalar_newtype](crate::impl_scalar_newtype), is a `ScalarOperand` of ndarray,
+//! so that arrays can be multiplied by scalars as `&a * x` in generic code bounded by
+//! [NdScalar]. The constructors of this module fill arrays of any shape from the random
+//! number APIs of [Scalar].
+//!
+//! ```
+//! use cauchy::{c64, ndarray::{random_seeded, NdScalar}, Scalar};
+//! use ndarray::Array2;
+//!
+//! fn normalize<A: NdScalar>(a: &Array2<A>) -> Array2<A> {
+//!     let norm = Scalar::sqrt(a.iter().map(|x| x.square()).sum::<A::Real>());
+//!     a / A::from_real(norm)
+//! }
+//!
+//! let a: Array2<c64> = random_seeded((3, 4), 42);
+//! let n = normalize(&a);
+//! assert!((n.iter().map(|x| x.square()).sum::<f64>() - 1.0).abs() < 1e-12);
+//! // seeded arrays do not depend on the memory order
+//! assert_eq!(random_seeded::<c64, _, _>((3, 4), 42), a);
+//! ```
+
+use crate::{sum::from_components, RealScalar, Scalar};
+use ::ndarray::{
+    Array, ArrayBase, Data, Dimension, IntoDimension, LinalgScalar, ScalarOperand, ShapeBuilder,
+    Zip,
+};
+use rand::{distributions::Distribution, Rng};
+
+/// [Scalar] usable in the arithmetic of ndarray, with a real type which is as well
+pub trait NdScalar:
+    Scalar<Real = <Self as NdScalar>::NdReal> + ScalarOperand + LinalgScalar
+{
+    /// Real type shared by [Scalar] and ndarray
+    type NdReal: RealScalar + ScalarOperand + LinalgScalar;
+}
+
+impl<A> NdScalar for A
+where
+    A: Scalar + ScalarOperand + LinalgScalar,
+    A::Real: ScalarOperand + LinalgScalar,
+{
+    type NdReal = A::Real;
+}
+
+/// Array of random numbers of [Scalar::rand]
+pub fn random<A, Sh, D>(shape: Sh, rng: &mut impl Rng) -> Array<A, D>
+where
+    A: Scalar,
+    Sh: ShapeBuilder<Dim = D>,
+    D: Dimension,
+{
+    Array::from_shape_simple_fn(shape, || A::rand(rng))
+}
+
+/// Array of random numbers drawn from `dist` as by [Scalar::rand_using]
+pub fn random_using<A, Sh, D>(
+    shape: Sh,
+    rng: &mut impl Rng,
+    dist: impl Distribution<A>,
+) -> Array<A, D>
+where
+    A: Scalar,
+    Sh: ShapeBuilder<Dim = D>,
+    D: Dimension,
+{
+    Array::from_shape_simple_fn(shape, || dist.sample(rng))
+}
+
+/// Array of random numbers of [Scalar::rand_normal]
+pub fn random_normal<A, Sh, D>(shape: Sh, rng: &mut impl Rng) -> Array<A, D>
+where
+    A: Scalar,
+    Sh: ShapeBuilder<Dim = D>,
+    D: Dimension,
+{
+    Array::from_shape_simple_fn(shape, || A::rand_normal(rng))
+}
+
+/// Array of [Scalar::rand_seeded] of `seed`, indexed by the position in row-major order
+/// regardless of the memory order of `shape`
+pub fn random_seeded<A, Sh, D>(shape: Sh, seed: u64) -> Array<A, D>
+where
+    A: Scalar,
+    Sh: ShapeBuilder<Dim = D>,
+    D: Dimension,
+{
+    let shape = shape.into_shape_with_order();
+    let strides = shape.raw_dim().default_strides();
+    Array::from_shape_fn(shape, |index| {
+        let index = index.into_dimension();
+        let position = (0..index.ndim())
+            .map(|i| index[i] * strides[i])
+            .sum::<usize>();
+        A::rand_seeded(seed, position as u64)
+    })
+}
+
+/// Array of scalars with the real and imaginary parts from arrays of the same shape,
+/// ignoring `im` for real types
+///
+/// Panics if the shapes differ.
+pub fn from_parts<A, S, T, D>(re: &ArrayBase<S, D>, im: &ArrayBase<T, D>) -> Array<A, D>
+where
+    A: Scalar,
+    S: Data<Elem = A::Real>,
+    T: Data<Elem = A::Real>,
+    D: Dimension,
+{
+    Zip::from(re)
+        .and(im)
+        .map_collect(|&re, &im| from_components(re, im))
+}
@@ -0,0 +1,523 @@
+//! Delegating implementation of [Scalar](crate::Scalar) for newtypes
+
+/// Implement [Scalar](crate::Scalar), its supertraits and [ScalarLayout](crate::ScalarLayout)
+/// for a newtype over `f32`, `f64`, `c32` or `c64` by delegating to the wrapped value
+///
+/// The newtype has to derive `Debug`, `Clone`, `Copy` and `PartialEq` itself.
+/// Its associated types are those of the wrapped type, e.g. `Real = f64` for a wrapped `f64`,
+/// so that only operations on the whole scalar keep the newtype.
+///
+/// ```
+/// use cauchy::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Meter(f64);
+/// cauchy::impl_scalar_newtype!(Meter, f64);
+///
+/// fn hypot<A: Scalar>(a: A, b: A) -> A {
+///     (a * a + b * b).sqrt()
+/// }
+/// assert_eq!(hypot(Meter(3.0), Meter(4.0)), Meter(5.0));
+/// assert_eq!(Meter(2.0).mul_real(1.5), Meter(3.0));
+/// assert_eq!(Meter::parse("2.5"), Ok(Meter(2.5)));
+/// ```
+#[macro_export]
+macro_rules! impl_scalar_newtype {
+    ($name:ident, $inner:ty) => {
+        $crate::__impl_newtype_ops!($name, $inner);
+        $crate::__impl_newtype_num!($name, $inner);
+        $crate::__impl_newtype_approx!($name, $inner);
+        $crate::__impl_newtype_rkyv!($name, $inner);
+        $crate::__impl_newtype_ndarray!($name);
+        $crate::__impl_newtype_scalar!($name, $inner);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_ops {
+    ($name:ident, $inner:ty) => {
+        $crate::__impl_newtype_ops!(@binary $name, Add, add, AddAssign, add_assign);
+        $crate::__impl_newtype_ops!(@binary $name, Sub, sub, SubAssign, sub_assign);
+        $crate::__impl_newtype_ops!(@binary $name, Mul, mul, MulAssign, mul_assign);
+        $crate::__impl_newtype_ops!(@binary $name, Div, div, DivAssign, div_assign);
+        $crate::__impl_newtype_ops!(@binary $name, Rem, rem, RemAssign, rem_assign);
+
+        impl core::ops::Neg for $name {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                $name(-self.0)
+            }
+        }
+        impl core::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                $name(iter.map(|x| x.0).sum())
+            }
+        }
+        impl core::iter::Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                $name(iter.map(|x| x.0).product())
+            }
+        }
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+        impl core::fmt::LowerExp for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::LowerExp::fmt(&self.0, f)
+            }
+        }
+        impl core::fmt::UpperExp for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::UpperExp::fmt(&self.0, f)
+            }
+        }
+    };
+    (@binary $name:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl core::ops::$op for $name {
+            type Output = Self;
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                $name(core::ops::$op::$method(self.0, rhs.0))
+            }
+        }
+        impl core::ops::$op_assign for $name {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                core::ops::$op_assign::$method_assign(&mut self.0, rhs.0)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_num {
+    ($name:ident, $inner:ty) => {
+        impl $crate::__private::num_traits::Zero for $name {
+            #[inline]
+            fn zero() -> Self {
+                $name(<$inner as $crate::__private::num_traits::Zero>::zero())
+            }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                $crate::__private::num_traits::Zero::is_zero(&self.0)
+            }
+        }
+        impl $crate::__private::num_traits::One for $name {
+            #[inline]
+            fn one() -> Self {
+                $name(<$inner as $crate::__private::num_traits::One>::one())
+            }
+        }
+        impl $crate::ConstZero for $name {
+            const ZERO: Self = $name(<$inner as $crate::ConstZero>::ZERO);
+        }
+        impl $crate::ConstOne for $name {
+            const ONE: Self = $name(<$inner as $crate::ConstOne>::ONE);
+        }
+        impl $crate::__private::num_traits::Num for $name {
+            type FromStrRadixErr = <$inner as $crate::__private::num_traits::Num>::FromStrRadixErr;
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                <$inner as $crate::__private::num_traits::Num>::from_str_radix(s, radix).map($name)
+            }
+        }
+        impl $crate::__private::num_traits::ToPrimitive for $name {
+            fn to_i64(&self) -> Option<i64> {
+                $crate::__private::num_traits::ToPrimitive::to_i64(&self.0)
+            }
+            fn to_u64(&self) -> Option<u64> {
+                $crate::__private::num_traits::ToPrimitive::to_u64(&self.0)
+            }
+            fn to_f32(&self) -> Option<f32> {
+                $crate::__private::num_traits::ToPrimitive::to_f32(&self.0)
+            }
+            fn to_f64(&self) -> Option<f64> {
+                $crate::__private::num_traits::ToPrimitive::to_f64(&self.0)
+            }
+        }
+        impl $crate::__private::num_traits::FromPrimitive for $name {
+            fn from_i64(n: i64) -> Option<Self> {
+                <$inner as $crate::__private::num_traits::FromPrimitive>::from_i64(n).map($name)
+            }
+            fn from_u64(n: u64) -> Option<Self> {
+                <$inner as $crate::__private::num_traits::FromPrimitive>::from_u64(n).map($name)
+            }
+            fn from_f32(n: f32) -> Option<Self> {
+                <$inner as $crate::__private::num_traits::FromPrimitive>::from_f32(n).map($name)
+            }
+            fn from_f64(n: f64) -> Option<Self> {
+                <$inner as $crate::__private::num_traits::FromPrimitive>::from_f64(n).map($name)
+            }
+        }
+        impl $crate::__private::num_traits::NumCast for $name {
+            fn from<T: $crate::__private::num_traits::ToPrimitive>(n: T) -> Option<Self> {
+                <$inner as $crate::__private::num_traits::NumCast>::from(n).map($name)
+            }
+        }
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                <$inner as $crate::__private::serde::Deserialize>::deserialize(deserializer)
+                    .map($name)
+            }
+        }
+        impl $crate::NumScalar for $name {
+            fn rand(rng: &mut impl $crate::__private::rand::Rng) -> Self {
+                $name(<$inner as $crate::NumScalar>::rand(rng))
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "approx")]
+#[macro_export]
+macro_rules! __impl_newtype_approx {
+    ($name:ident, $inner:ty) => {
+        impl $crate::__private::approx::AbsDiffEq for $name {
+            type Epsilon = <$inner as $crate::__private::approx::AbsDiffEq>::Epsilon;
+            fn default_epsilon() -> Self::Epsilon {
+                <$inner as $crate::__private::approx::AbsDiffEq>::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $crate::__private::approx::AbsDiffEq::abs_diff_eq(&self.0, &other.0, epsilon)
+            }
+        }
+        impl $crate::__private::approx::RelativeEq for $name {
+            fn default_max_relative() -> Self::Epsilon {
+                <$inner as $crate::__private::approx::RelativeEq>::default_max_relative()
+            }
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $crate::__private::approx::RelativeEq::relative_eq(
+                    &self.0,
+                    &other.0,
+                    epsilon,
+                    max_relative,
+                )
+            }
+        }
+        impl $crate::__private::approx::UlpsEq for $name {
+            fn default_max_ulps() -> u32 {
+                <$inner as $crate::__private::approx::UlpsEq>::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $crate::__private::approx::UlpsEq::ulps_eq(&self.0, &other.0, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "approx"))]
+#[macro_export]
+macro_rules! __impl_newtype_approx {
+    ($name:ident, $inner:ty) => {};
+}
+
+#[doc(hidden)]
+#[cfg(feature = "rkyv")]
+#[macro_export]
+macro_rules! __impl_newtype_rkyv {
+    ($name:ident, $inner:ty) => {
+        impl $crate::__private::rkyv::Archive for $name {
+            type Archived = Self;
+            type Resolver = ();
+            #[inline]
+            unsafe fn resolve(&self, _: usize, _: (), out: *mut Self) {
+                out.write(*self);
+            }
+        }
+        impl<S: $crate::__private::rkyv::Fallible + ?Sized> $crate::__private::rkyv::Serialize<S>
+            for $name
+        {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+        impl<D: $crate::__private::rkyv::Fallible + ?Sized>
+            $crate::__private::rkyv::Deserialize<$name, D> for $name
+        {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> Result<$name, D::Error> {
+                Ok(*self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "rkyv"))]
+#[macro_export]
+macro_rules! __impl_newtype_rkyv {
+    ($name:ident, $inner:ty) => {};
+}
+
+#[doc(hidden)]
+#[cfg(feature = "ndarray")]
+#[macro_export]
+macro_rules! __impl_newtype_ndarray {
+    ($name:ident) => {
+        impl $crate::__private::ndarray::ScalarOperand for $name {}
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "ndarray"))]
+#[macro_export]
+macro_rules! __impl_newtype_ndarray {
+    ($name:ident) => {};
+}
+
+#[doc(hidden)]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! __impl_newtype_std {
+    ($name:ident, $inner:ty) => {
+        fn to_hex_string(&self) -> $crate::__private::String {
+            <$inner as $crate::Scalar>::to_hex_string(&self.0)
+        }
+        fn to_shortest_string(&self) -> $crate::__private::String {
+            <$inner as $crate::Scalar>::to_shortest_string(&self.0)
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! __impl_newtype_std {
+    ($name:ident, $inner:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_scalar {
+    ($name:ident, $inner:ty) => {
+        impl $crate::Scalar for $name {
+            type Real = <$inner as $crate::Scalar>::Real;
+            type Complex = <$inner as $crate::Scalar>::Complex;
+            type Widened = <$inner as $crate::Scalar>::Widened;
+
+            const IS_COMPLEX: bool = <$inner as $crate::Scalar>::IS_COMPLEX;
+            const COMPONENTS: usize = <$inner as $crate::Scalar>::COMPONENTS;
+            const I: Self::Complex = <$inner as $crate::Scalar>::I;
+            const PI: Self::Real = <$inner as $crate::Scalar>::PI;
+            const E: Self::Real = <$inner as $crate::Scalar>::E;
+            const EPSILON: Self::Real = <$inner as $crate::Scalar>::EPSILON;
+
+            $crate::__impl_newtype_std!($name, $inner);
+            $crate::__impl_newtype_scalar!(@assoc $inner,
+                real<T: $crate::__private::num_traits::ToPrimitive>(re: T) -> Self::Real;
+                complex<T: $crate::__private::num_traits::ToPrimitive>(re: T, im: T) -> Self::Complex;
+                try_real<T: $crate::__private::num_traits::ToPrimitive>(re: T) -> Option<Self::Real>;
+                try_complex<T: $crate::__private::num_traits::ToPrimitive>(re: T, im: T)
+                    -> Option<Self::Complex>;
+                cis(theta: Self::Real) -> Self::Complex;
+                i() -> Self::Complex;
+                from_parts(parts: (Self::Real, Self::Real)) -> Self::Complex;
+            );
+            $crate::__impl_newtype_scalar!(@wrap $name, $inner,
+                from_real(re: Self::Real);
+                narrow(w: Self::Widened);
+                rand_seeded(seed: u64, index: u64);
+                qrand(seq: &mut $crate::Halton);
+                rand_normal(rng: &mut impl $crate::__private::rand::Rng);
+                rand_cnormal(rng: &mut impl $crate::__private::rand::Rng);
+                rand_unit(rng: &mut impl $crate::__private::rand::Rng);
+                rand_disk(rng: &mut impl $crate::__private::rand::Rng);
+                rand_exp(rng: &mut impl $crate::__private::rand::Rng, lambda: Self::Real);
+                rand_gamma(
+                    rng: &mut impl $crate::__private::rand::Rng,
+                    shape: Self::Real,
+                    scale: Self::Real
+                );
+                rand_range(rng: &mut impl $crate::__private::rand::Rng, lo: Self::Real, hi: Self::Real);
+            );
+            $crate::__impl_newtype_scalar!(@unwrap $inner,
+                widen(self) -> Self::Widened;
+                abs(self) -> Self::Real;
+                square(self) -> Self::Real;
+                mul_i(self) -> Self::Complex;
+                div_i(self) -> Self::Complex;
+                expi(self) -> Self::Complex;
+                add_complex(self, im: Self::Complex) -> Self::Complex;
+                sub_complex(self, im: Self::Complex) -> Self::Complex;
+                mul_complex(self, im: Self::Complex) -> Self::Complex;
+                div_complex(self, im: Self::Complex) -> Self::Complex;
+                powc(self, n: Self::Complex) -> Self::Complex;
+                powf_c(self, n: Self::Real) -> Self::Complex;
+            );
+            $crate::__impl_newtype_scalar!(@unary $name, $inner,
+                proj sqrt rsqrt exp ln sin cos tan asin acos atan sinh cosh tanh asinh acosh atanh
+                erf erfc gamma ln_gamma to_degrees to_radians
+            );
+            $crate::__impl_newtype_scalar!(@with_real $name, $inner,
+                add_real sub_real mul_real div_real fmod rem_euclid powf log
+            );
+            $crate::__impl_newtype_scalar!(@binary $name, $inner,
+                conj_mul midpoint pow logaddexp
+            );
+
+            fn parse(s: &str) -> Result<Self, $crate::ParseScalarError> {
+                <$inner as $crate::Scalar>::parse(s).map($name)
+            }
+            fn from_hex_str(s: &str) -> Result<Self, $crate::ParseScalarError> {
+                <$inner as $crate::Scalar>::from_hex_str(s).map($name)
+            }
+            fn try_from_complex(c: Self::Complex) -> Option<Self> {
+                <$inner as $crate::Scalar>::try_from_complex(c).map($name)
+            }
+            fn lerp(self, other: Self, t: Self::Real) -> Self {
+                $name(<$inner as $crate::Scalar>::lerp(self.0, other.0, t))
+            }
+            fn modf(self) -> (Self, Self) {
+                let (int, frac) = <$inner as $crate::Scalar>::modf(self.0);
+                ($name(int), $name(frac))
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = <$inner as $crate::Scalar>::sin_cos(self.0);
+                ($name(sin), $name(cos))
+            }
+            fn powi(self, n: i32) -> Self {
+                $name(<$inner as $crate::Scalar>::powi(self.0, n))
+            }
+            fn frexp(self) -> (Self, i32) {
+                let (m, e) = <$inner as $crate::Scalar>::frexp(self.0);
+                ($name(m), e)
+            }
+            fn ldexp(self, exp: i32) -> Self {
+                $name(<$inner as $crate::Scalar>::ldexp(self.0, exp))
+            }
+            fn re(&self) -> Self::Real {
+                <$inner as $crate::Scalar>::re(&self.0)
+            }
+            fn im(&self) -> Self::Real {
+                <$inner as $crate::Scalar>::im(&self.0)
+            }
+            fn as_c(&self) -> Self::Complex {
+                <$inner as $crate::Scalar>::as_c(&self.0)
+            }
+            fn conj(&self) -> Self {
+                $name(<$inner as $crate::Scalar>::conj(&self.0))
+            }
+            fn is_normal(&self) -> bool {
+                <$inner as $crate::Scalar>::is_normal(&self.0)
+            }
+            fn is_subnormal(&self) -> bool {
+                <$inner as $crate::Scalar>::is_subnormal(&self.0)
+            }
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                $name(<$inner as $crate::Scalar>::mul_add(self.0, a.0, b.0))
+            }
+            fn checked_sqrt(self) -> Option<Self> {
+                <$inner as $crate::Scalar>::checked_sqrt(self.0).map($name)
+            }
+            fn beta(a: Self, b: Self) -> Self {
+                $name(<$inner as $crate::Scalar>::beta(a.0, b.0))
+            }
+            fn fill_rand(slice: &mut [Self], rng: &mut impl $crate::__private::rand::Rng) {
+                for x in slice {
+                    *x = <Self as $crate::NumScalar>::rand(rng);
+                }
+            }
+        }
+
+        impl $crate::ScalarLayout for $name {
+            type Bits = <$inner as $crate::ScalarLayout>::Bits;
+            type Bytes = <$inner as $crate::ScalarLayout>::Bytes;
+
+            fn complex_as_real_slice(xs: &[Self::Complex]) -> &[Self::Real] {
+                <$inner as $crate::ScalarLayout>::complex_as_real_slice(xs)
+            }
+            fn complex_as_real_slice_mut(xs: &mut [Self::Complex]) -> &mut [Self::Real] {
+                <$inner as $crate::ScalarLayout>::complex_as_real_slice_mut(xs)
+            }
+            fn real_as_complex_slice(xs: &[Self::Real]) -> Option<&[Self::Complex]> {
+                <$inner as $crate::ScalarLayout>::real_as_complex_slice(xs)
+            }
+            fn real_as_complex_slice_mut(xs: &mut [Self::Real]) -> Option<&mut [Self::Complex]> {
+                <$inner as $crate::ScalarLayout>::real_as_complex_slice_mut(xs)
+            }
+            fn to_bits(self) -> Self::Bits {
+                <$inner as $crate::ScalarLayout>::to_bits(self.0)
+            }
+            fn from_bits(bits: Self::Bits) -> Self {
+                $name(<$inner as $crate::ScalarLayout>::from_bits(bits))
+            }
+            fn to_le_bytes(self) -> Self::Bytes {
+                <$inner as $crate::ScalarLayout>::to_le_bytes(self.0)
+            }
+            fn to_be_bytes(self) -> Self::Bytes {
+                <$inner as $crate::ScalarLayout>::to_be_bytes(self.0)
+            }
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                $name(<$inner as $crate::ScalarLayout>::from_le_bytes(bytes))
+            }
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                $name(<$inner as $crate::ScalarLayout>::from_be_bytes(bytes))
+            }
+        }
+    };
+    (@assoc $inner:ty, $($f:ident $(<$g:ident: $b:path>)? ($($a:ident: $t:ty),*) -> $r:ty;)*) => {
+        $(
+            fn $f $(<$g: $b>)? ($($a: $t),*) -> $r {
+                <$inner as $crate::Scalar>::$f($($a),*)
+            }
+        )*
+    };
+    (@wrap $name:ident, $inner:ty, $($f:ident ($($a:ident: $t:ty),*);)*) => {
+        $(
+            fn $f($($a: $t),*) -> Self {
+                $name(<$inner as $crate::Scalar>::$f($($a),*))
+            }
+        )*
+    };
+    (@unwrap $inner:ty, $($f:ident (self $(, $a:ident: $t:ty)*) -> $r:ty;)*) => {
+        $(
+            fn $f(self $(, $a: $t)*) -> $r {
+                <$inner as $crate::Scalar>::$f(self.0 $(, $a)*)
+            }
+        )*
+    };
+    (@unary $name:ident, $inner:ty, $($f:ident)*) => {
+        $(
+            fn $f(self) -> Self {
+                $name(<$inner as $crate::Scalar>::$f(self.0))
+            }
+        )*
+    };
+    (@with_real $name:ident, $inner:ty, $($f:ident)*) => {
+        $(
+            fn $f(self, re: Self::Real) -> Self {
+                $name(<$inner as $crate::Scalar>::$f(self.0, re))
+            }
+        )*
+    };
+    (@binary $name:ident, $inner:ty, $($f:ident)*) => {
+        $(
+            fn $f(self, other: Self) -> Self {
+                $name(<$inner as $crate::Scalar>::$f(self.0, other.0))
+            }
+        )*
+    };
+}
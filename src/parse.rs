@@ -0,0 +1,130 @@
+//! Parsing real and complex numbers from strings, and formatting them back
+
+use core::fmt;
+use core::str::FromStr;
+use num_traits::Float;
+
+/// An error which can be returned when parsing a scalar by [crate::Scalar::parse]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseScalarError {
+    kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ErrorKind {
+    Empty,
+    Invalid,
+    NotReal,
+}
+
+impl ParseScalarError {
+    pub(crate) fn empty() -> Self {
+        ParseScalarError {
+            kind: ErrorKind::Empty,
+        }
+    }
+
+    pub(crate) fn invalid() -> Self {
+        ParseScalarError {
+            kind: ErrorKind::Invalid,
+        }
+    }
+
+    pub(crate) fn not_real() -> Self {
+        ParseScalarError {
+            kind: ErrorKind::NotReal,
+        }
+    }
+}
+
+impl fmt::Display for ParseScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ErrorKind::Empty => "cannot parse scalar from empty string",
+            ErrorKind::Invalid => "invalid scalar literal",
+            ErrorKind::NotReal => "non-zero imaginary part for real scalar",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseScalarError {}
+
+/// Parse a real number with an optional sign, where a missing number means one, as in `-i`
+fn parse_coefficient<T: Float + FromStr>(s: &str) -> Result<T, ParseScalarError> {
+    let s = s.trim();
+    let (neg, body) = match s.as_bytes().first() {
+        Some(b'+') => (false, s[1..].trim_start()),
+        Some(b'-') => (true, s[1..].trim_start()),
+        _ => (false, s),
+    };
+    if body.starts_with(['+', '-']) {
+        return Err(ParseScalarError::invalid());
+    }
+    let x = if body.is_empty() {
+        T::one()
+    } else {
+        body.parse().map_err(|_| ParseScalarError::invalid())?
+    };
+    Ok(if neg { -x } else { x })
+}
+
+fn parse_real<T: Float + FromStr>(s: &str) -> Result<T, ParseScalarError> {
+    let s = s.trim();
+    if s.is_empty() || s == "+" || s == "-" {
+        return Err(ParseScalarError::invalid());
+    }
+    parse_coefficient(s)
+}
+
+/// Split a string like `1.5`, `-2e-3+4.5i`, `3j`, or `(1, 2)` into the real and imaginary parts
+pub(crate) fn parse_parts<T: Float + FromStr>(s: &str) -> Result<(T, T), ParseScalarError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseScalarError::empty());
+    }
+    if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        let mut parts = inner.split(',');
+        return match (parts.next(), parts.next(), parts.next()) {
+            (Some(re), Some(im), None) => Ok((parse_real(re)?, parse_real(im)?)),
+            _ => Err(ParseScalarError::invalid()),
+        };
+    }
+    let body = match s.strip_suffix(['i', 'j']) {
+        Some(body) => body,
+        None => return Ok((parse_real(s)?, T::zero())),
+    };
+    // the imaginary part starts at the last sign which is not in an exponent
+    let bytes = body.as_bytes();
+    let split = (1..bytes.len())
+        .rev()
+        .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'e' | b'E'));
+    match split {
+        Some(k) => Ok((parse_real(&body[..k])?, parse_coefficient(&body[k..])?)),
+        None => Ok((T::zero(), parse_coefficient(body)?)),
+    }
+}
+
+/// Parse a real number, rejecting a non-zero imaginary part
+pub(crate) fn parse_real_scalar<T: Float + FromStr>(s: &str) -> Result<T, ParseScalarError> {
+    let (re, im) = parse_parts::<T>(s)?;
+    if im.is_zero() {
+        Ok(re)
+    } else {
+        Err(ParseScalarError::not_real())
+    }
+}
+
+/// Format a real number with the shortest digits which parse back to it,
+/// in decimal or scientific notation whichever is shorter
+#[cfg(feature = "std")]
+pub(crate) fn shortest<T: fmt::Display + fmt::LowerExp>(x: T) -> String {
+    let decimal = x.to_string();
+    let scientific = format!("{:e}", x);
+    if scientific.len() < decimal.len() {
+        scientific
+    } else {
+        decimal
+    }
+}
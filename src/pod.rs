@@ -0,0 +1,70 @@
+//! Zero-copy casts of slices of scalars with [bytemuck](https://docs.rs/bytemuck)
+//!
+//! All of `f32`, `f64`, `c32` and `c64` are [Pod], so that buffers can be handed to GPU APIs
+//! or memory-mapped files without unsafe code.
+//!
+//! ```
+//! use cauchy::{c64, pod};
+//!
+//! let zs = [c64::new(1.0, 2.0), c64::new(3.0, 4.0)];
+//! let bytes = pod::as_bytes(&zs);
+//! assert_eq!(bytes.len(), 32);
+//!
+//! let xs: &[f64] = pod::complex_as_real(&zs);
+//! assert_eq!(xs, &[1.0, 2.0, 3.0, 4.0]);
+//! assert_eq!(pod::try_real_as_complex(xs), Ok(&zs[..]));
+//! assert!(pod::try_real_as_complex(&xs[..3]).is_err());
+//! ```
+
+use crate::{c32, c64, Scalar};
+use bytemuck::{Pod, PodCastError};
+use num_complex::Complex;
+
+const _: fn() = || {
+    fn assert_pod<A: Scalar + Pod>() {}
+    assert_pod::<f32>();
+    assert_pod::<f64>();
+    assert_pod::<c32>();
+    assert_pod::<c64>();
+};
+
+/// View scalars as their bytes in native byte order
+pub fn as_bytes<A: Scalar + Pod>(xs: &[A]) -> &[u8] {
+    bytemuck::cast_slice(xs)
+}
+
+/// View scalars as their mutable bytes in native byte order
+pub fn as_bytes_mut<A: Scalar + Pod>(xs: &mut [A]) -> &mut [u8] {
+    bytemuck::cast_slice_mut(xs)
+}
+
+/// View bytes in native byte order as scalars,
+/// failing if the length or the alignment of `bytes` does not fit
+pub fn try_from_bytes<A: Scalar + Pod>(bytes: &[u8]) -> Result<&[A], PodCastError> {
+    bytemuck::try_cast_slice(bytes)
+}
+
+/// Mutable version of [try_from_bytes]
+pub fn try_from_bytes_mut<A: Scalar + Pod>(bytes: &mut [u8]) -> Result<&mut [A], PodCastError> {
+    bytemuck::try_cast_slice_mut(bytes)
+}
+
+/// View complex numbers as interleaved real and imaginary parts
+pub fn complex_as_real<T: Pod>(xs: &[Complex<T>]) -> &[T] {
+    bytemuck::cast_slice(xs)
+}
+
+/// Mutable version of [complex_as_real]
+pub fn complex_as_real_mut<T: Pod>(xs: &mut [Complex<T>]) -> &mut [T] {
+    bytemuck::cast_slice_mut(xs)
+}
+
+/// View interleaved real and imaginary parts as complex numbers, failing for odd lengths
+pub fn try_real_as_complex<T: Pod>(xs: &[T]) -> Result<&[Complex<T>], PodCastError> {
+    bytemuck::try_cast_slice(xs)
+}
+
+/// Mutable version of [try_real_as_complex]
+pub fn try_real_as_complex_mut<T: Pod>(xs: &mut [T]) -> Result<&mut [Complex<T>], PodCastError> {
+    bytemuck::try_cast_slice_mut(xs)
+}
@@ -0,0 +1,73 @@
+//! Complex numbers in polar form, serialized as `{ "r": .., "theta": .. }`
+//!
+//! Fields of complex type can use `#[serde(with = "cauchy::polar")]`:
+//!
+//! ```
+//! use cauchy::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Calibration {
+//!     #[serde(with = "cauchy::polar")]
+//!     gain: c64,
+//! }
+//!
+//! let json = serde_json::to_string(&Calibration { gain: c64::new(0.0, 2.0) }).unwrap();
+//! assert_eq!(json, format!(r#"{{"gain":{{"r":2.0,"theta":{}}}}}"#, std::f64::consts::FRAC_PI_2));
+//! let cal: Calibration = serde_json::from_str(r#"{"gain": {"r": 2.0, "theta": 0.0}}"#).unwrap();
+//! assert_eq!(cal.gain, c64::new(2.0, 0.0));
+//! ```
+
+use crate::Scalar;
+use num_traits::Float;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Magnitude `r` and phase `theta` of a complex number `r (cos(theta) + i sin(theta))`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Polar<T> {
+    pub r: T,
+    pub theta: T,
+}
+
+impl<T: Scalar<Real = T> + Float> Polar<T> {
+    /// Decompose a complex number, with the phase in `[-pi, pi]` as [ComplexScalar::arg](crate::ComplexScalar::arg)
+    ///
+    /// The phase is `-pi` on the negative real axis with a negative zero imaginary part:
+    ///
+    /// ```
+    /// use cauchy::{c64, polar::Polar};
+    /// use std::f64::consts::PI;
+    ///
+    /// assert_eq!(Polar::<f64>::from_complex(c64::new(-1.0, 0.0)).theta, PI);
+    /// assert_eq!(Polar::<f64>::from_complex(c64::new(-1.0, -0.0)).theta, -PI);
+    /// ```
+    pub fn from_complex(z: T::Complex) -> Self {
+        Polar {
+            r: z.abs(),
+            theta: Float::atan2(z.im(), z.re()),
+        }
+    }
+
+    /// Compose the complex number
+    pub fn to_complex(self) -> T::Complex {
+        T::from_polar(self.r, self.theta)
+    }
+}
+
+/// Serialize a complex number as [Polar]
+pub fn serialize<A, S>(z: &A, serializer: S) -> Result<S::Ok, S::Error>
+where
+    A: Scalar<Complex = A>,
+    S: Serializer,
+{
+    Polar::<A::Real>::from_complex(*z).serialize(serializer)
+}
+
+/// Deserialize a complex number from [Polar]
+pub fn deserialize<'de, A, D>(deserializer: D) -> Result<A, D::Error>
+where
+    A: Scalar<Complex = A>,
+    D: Deserializer<'de>,
+{
+    Polar::<A::Real>::deserialize(deserializer).map(Polar::to_complex)
+}
@@ -0,0 +1,46 @@
+//! Evaluation of polynomials
+
+use crate::Scalar;
+
+/// `acc * x + c`, fused if the target has FMA instructions
+#[inline]
+fn step<A: Scalar>(acc: A, x: A, c: A) -> A {
+    if cfg!(target_feature = "fma") {
+        acc.mul_add(x, c)
+    } else {
+        acc * x + c
+    }
+}
+
+/// Evaluate the polynomial `coeffs[0] + coeffs[1] x + coeffs[2] x^2 + ...` by Horner's scheme
+///
+/// ```
+/// use cauchy::poly::horner;
+///
+/// // 1 + 2x + 3x^2
+/// assert_eq!(horner(&[1.0, 2.0, 3.0], 2.0), 17.0);
+/// assert_eq!(horner::<f64>(&[], 2.0), 0.0);
+/// ```
+pub fn horner<A: Scalar>(coeffs: &[A], x: A) -> A {
+    coeffs
+        .iter()
+        .rev()
+        .fold(A::zero(), |acc, &c| step(acc, x, c))
+}
+
+/// Evaluate the polynomial as [horner] together with its derivative
+///
+/// ```
+/// use cauchy::poly::horner_deriv;
+///
+/// // 1 + 2x + 3x^2 and 2 + 6x
+/// assert_eq!(horner_deriv(&[1.0, 2.0, 3.0], 2.0), (17.0, 14.0));
+/// ```
+pub fn horner_deriv<A: Scalar>(coeffs: &[A], x: A) -> (A, A) {
+    coeffs
+        .iter()
+        .rev()
+        .fold((A::zero(), A::zero()), |(p, dp), &c| {
+            (step(p, x, c), step(dp, x, p))
+        })
+}
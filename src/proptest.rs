@@ -0,0 +1,70 @@
+//! Strategies generating scalars for property tests with [proptest](https://docs.rs/proptest)
+//!
+//! Every strategy is generic over `A: Scalar` and generates complex numbers component-wise,
+//! shrinking towards zero or the real axis.
+//!
+//! ```
+//! use cauchy::{c64, proptest::{finite, unit}, Scalar};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(z in finite::<c64>(), u in unit::<c64>())| {
+//!     prop_assert_eq!(z.conj().conj(), z);
+//!     prop_assert!((Scalar::abs(z * u) - Scalar::abs(z)).abs() <= 1e-15 * Scalar::abs(z));
+//! });
+//! ```
+
+use crate::{
+    generate::{unit_from, well_conditioned_from},
+    sum::from_components,
+    RealScalar, Scalar,
+};
+use ::proptest::prelude::*;
+use num_traits::Float;
+
+/// Finite real numbers, zero or normal over the whole exponent range
+fn finite_real<R: RealScalar>() -> impl Strategy<Value = R> {
+    let (_, max_exp) = Scalar::frexp(R::max_value());
+    let (_, min_exp) = Scalar::frexp(R::min_positive_value());
+    let normal = (any::<bool>(), 0.5..1.0_f64, min_exp..=max_exp).prop_map(|(neg, m, e)| {
+        // the mantissa may round up to one in lower precision
+        let x = Float::min(Scalar::ldexp(R::real(m), e), R::max_value());
+        if neg {
+            -x
+        } else {
+            x
+        }
+    });
+    prop_oneof![1 => Just(R::zero()), 9 => normal]
+}
+
+/// Assemble from strategies for the real and imaginary parts, ignoring the latter for real types
+fn from_parts<A: Scalar>(
+    re: impl Strategy<Value = A::Real>,
+    im: impl Strategy<Value = A::Real>,
+) -> impl Strategy<Value = A> {
+    (re, im).prop_map(|(re, im)| from_components(re, im))
+}
+
+/// Any finite scalar, with components of any magnitude
+pub fn finite<A: Scalar>() -> impl Strategy<Value = A> {
+    from_parts(finite_real::<A::Real>(), finite_real::<A::Real>())
+}
+
+/// Scalars with components in `[-max, max]`, distributed uniformly
+pub fn bounded<A: Scalar>(max: f64) -> impl Strategy<Value = A> {
+    from_parts(
+        (-max..=max).prop_map(A::real),
+        (-max..=max).prop_map(A::real),
+    )
+}
+
+/// Scalars of absolute value one, `±1` for real types
+pub fn unit<A: Scalar>() -> impl Strategy<Value = A> {
+    (any::<bool>(), -1.0..1.0_f64).prop_map(|(neg, t)| unit_from(neg, A::real(t)))
+}
+
+/// Non-zero scalars of absolute value in `[1/16, 16]`, safe to divide by and to take
+/// logarithms of without cancellation or overflow
+pub fn well_conditioned<A: Scalar>() -> impl Strategy<Value = A> {
+    (unit::<A>(), -4.0..=4.0_f64).prop_map(|(u, e)| well_conditioned_from(u, e))
+}
@@ -0,0 +1,117 @@
+//! Conversions to and from Python with [pyo3](https://docs.rs/pyo3)
+//!
+//! Real numbers convert to and from Python `float` and complex numbers to and from `complex`,
+//! accepting any object with `__float__` or `__complex__` respectively. [PyScalar] adds
+//! copies from and to the buffers of objects like `array.array` or NumPy arrays, where
+//! complex numbers are the `Zf` and `Zd` elements of `complex64` and `complex128` arrays.
+//!
+//! ```
+//! use cauchy::{c64, pyo3::PyScalar};
+//! use pyo3::{ffi::c_str, prelude::*};
+//!
+//! Python::initialize();
+//! Python::attach(|py| -> PyResult<()> {
+//!     let z: c64 = py.eval(c_str!("complex(1, -2) * 2"), None, None)?.extract()?;
+//!     assert_eq!(z, c64::new(2.0, -4.0));
+//!     assert_eq!(c64::new(0.5, 1.0).into_pyobject(py)?.repr()?.to_str()?, "(0.5+1j)");
+//!
+//!     let array = py.eval(c_str!("__import__('array').array('d', [1, 2, 3])"), None, None)?;
+//!     assert_eq!(f64::from_buffer(&array)?, vec![1.0, 2.0, 3.0]);
+//!     f64::copy_to_buffer(&[4.0, 5.0, 6.0], &array)?;
+//!     assert_eq!(array.repr()?.to_str()?, "array('d', [4.0, 5.0, 6.0])");
+//!     Ok(())
+//! })
+//! .unwrap();
+//! ```
+
+use crate::{c32, c64, Scalar};
+use ::pyo3::{buffer::Element, buffer::PyBuffer, prelude::*};
+use core::ffi::CStr;
+
+/// [Scalar] converting to and from Python objects and buffers
+pub trait PyScalar:
+    Scalar + for<'a, 'py> FromPyObject<'a, 'py> + for<'py> IntoPyObject<'py>
+{
+    /// Copy the elements of an object supporting the buffer protocol, failing unless the
+    /// buffer has the element type of `Self`
+    fn from_buffer(obj: &Bound<'_, PyAny>) -> PyResult<Vec<Self>>;
+    /// Copy into the writable buffer of an object, which must have the same length as `xs`
+    fn copy_to_buffer(xs: &[Self], obj: &Bound<'_, PyAny>) -> PyResult<()>;
+}
+
+/// Element of complex buffers, laid out as the complex numbers of this crate
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct BufferComplex<R> {
+    re: R,
+    im: R,
+}
+
+/// Whether `format` describes native complex numbers with components of format `real`
+fn is_complex_format(format: &CStr, real: u8) -> bool {
+    let native = if cfg!(target_endian = "little") {
+        b'<'
+    } else {
+        b'>'
+    };
+    match *format.to_bytes() {
+        [b'Z', c] => c == real,
+        [prefix, b'Z', c] => (prefix == b'@' || prefix == b'=' || prefix == native) && c == real,
+        _ => false,
+    }
+}
+
+// SAFETY: the layout of `BufferComplex` is that of the C99 complex types of the formats
+unsafe impl Element for BufferComplex<f32> {
+    fn is_compatible_format(format: &CStr) -> bool {
+        is_complex_format(format, b'f')
+    }
+}
+
+// SAFETY: the layout of `BufferComplex` is that of the C99 complex types of the formats
+unsafe impl Element for BufferComplex<f64> {
+    fn is_compatible_format(format: &CStr) -> bool {
+        is_complex_format(format, b'd')
+    }
+}
+
+macro_rules! impl_py_real {
+    ($real:ty) => {
+        impl PyScalar for $real {
+            fn from_buffer(obj: &Bound<'_, PyAny>) -> PyResult<Vec<Self>> {
+                PyBuffer::<$real>::get(obj)?.to_vec(obj.py())
+            }
+            fn copy_to_buffer(xs: &[Self], obj: &Bound<'_, PyAny>) -> PyResult<()> {
+                PyBuffer::<$real>::get(obj)?.copy_from_slice(obj.py(), xs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_py_complex {
+    ($real:ty, $complex:ty) => {
+        impl PyScalar for $complex {
+            fn from_buffer(obj: &Bound<'_, PyAny>) -> PyResult<Vec<Self>> {
+                let buffer = PyBuffer::<BufferComplex<$real>>::get(obj)?;
+                let zs = buffer.to_vec(obj.py())?;
+                Ok(zs
+                    .into_iter()
+                    .map(|z| <$complex>::new(z.re, z.im))
+                    .collect())
+            }
+            fn copy_to_buffer(xs: &[Self], obj: &Bound<'_, PyAny>) -> PyResult<()> {
+                let buffer = PyBuffer::<BufferComplex<$real>>::get(obj)?;
+                let zs: Vec<_> = xs
+                    .iter()
+                    .map(|z| BufferComplex { re: z.re, im: z.im })
+                    .collect();
+                buffer.copy_from_slice(obj.py(), &zs)
+            }
+        }
+    };
+}
+
+impl_py_real!(f32);
+impl_py_real!(f64);
+impl_py_complex!(f32, c32);
+impl_py_complex!(f64, c64);
@@ -0,0 +1,71 @@
+//! Low-discrepancy sequences for quasi-Monte Carlo sampling
+
+use num_traits::Float;
+
+/// State of the two-dimensional Halton sequence in bases 2 and 3
+///
+/// Real numbers take the first coordinate of each point, which is the van der Corput sequence,
+/// and complex numbers take both as real and imaginary parts, all in `[0, 1)`.
+///
+/// ```
+/// use cauchy::*;
+///
+/// let mut seq = Halton::new();
+/// assert_eq!(f64::qrand(&mut seq), 0.5);
+/// assert_eq!(f64::qrand(&mut seq), 0.25);
+/// assert_eq!(c64::qrand(&mut seq), c64::new(0.75, 1.0 / 9.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Halton {
+    index: u64,
+}
+
+impl Default for Halton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Halton {
+    /// Start the sequence, skipping the point at the origin
+    pub fn new() -> Self {
+        Self::with_index(1)
+    }
+
+    /// Start the sequence at the point `index`, e.g. to split it across threads
+    pub fn with_index(index: u64) -> Self {
+        Halton { index }
+    }
+
+    /// Index of the next point
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Take the next point
+    pub(crate) fn next_point<T: Float>(&mut self) -> (T, T) {
+        let index = self.index;
+        self.index = self.index.wrapping_add(1);
+        (
+            to_unit(radical_inverse(index, 2)),
+            to_unit(radical_inverse(index, 3)),
+        )
+    }
+}
+
+/// Mirror the digits of `index` in `base` at the radix point, as a fraction `(numerator, denominator)`
+fn radical_inverse(mut index: u64, base: u64) -> (u64, u64) {
+    let (mut num, mut den) = (0_u64, 1_u64);
+    while index > 0 && den <= u64::MAX / base {
+        num = num * base + index % base;
+        den *= base;
+        index /= base;
+    }
+    (num, den)
+}
+
+/// Convert a fraction into `[0, 1)` even if it rounds up to one
+fn to_unit<T: Float>((num, den): (u64, u64)) -> T {
+    let x = T::from(num).unwrap() / T::from(den).unwrap();
+    x.min(T::one() - T::epsilon() / (T::one() + T::one()))
+}
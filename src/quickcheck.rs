@@ -0,0 +1,129 @@
+//! Scalars for property tests with [quickcheck](https://docs.rs/quickcheck)
+//!
+//! [Any] and [Finite] implement `Arbitrary` by wrapping the scalar, for the reason given in
+//! [crate::arbitrary]. Each component is uniform in `[-size, size]` for the size of the
+//! `Gen`, so that `Gen::new(n)` bounds the magnitudes of ordinary values by `n`. One in eight
+//! components is instead a special value such as zero, the largest finite number or, for
+//! [Any], infinity or NaN.
+//!
+//! A failing complex input first shrinks onto the real axis, so that a counterexample is
+//! reported as a real number whenever the imaginary part does not matter. The components
+//! then shrink separately towards zero via their absolute value, integer part and half.
+//!
+//! ```
+//! use cauchy::{c64, quickcheck::Finite, Scalar};
+//! use quickcheck::{quickcheck, Arbitrary};
+//!
+//! fn conj_involutive(Finite(z): Finite<c64>) -> bool {
+//!     z.conj().conj() == z
+//! }
+//! quickcheck(conj_involutive as fn(Finite<c64>) -> bool);
+//!
+//! // shrinking drops the imaginary part first
+//! let candidates: Vec<_> = Finite(c64::new(2.5, 3.0)).shrink().collect();
+//! assert_eq!(candidates[0], Finite(c64::new(2.5, 0.0)));
+//! ```
+
+use crate::{sum::from_components, RealScalar, Scalar};
+use ::quickcheck::{Arbitrary, Gen};
+use num_traits::{Float, Zero};
+
+/// Any scalar, including zeros of both signs, infinities, NaN and extreme magnitudes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Any<A>(pub A);
+
+/// Finite scalar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Finite<A>(pub A);
+
+/// Uniform in `[-size, size]`, or one of `special` with probability 1/8
+fn real<R: RealScalar>(g: &mut Gen, special: &[R]) -> R {
+    if !special.is_empty() && u8::arbitrary(g) % 8 == 0 {
+        return *g.choose(special).unwrap();
+    }
+    let t = u32::arbitrary(g) as f64 / u32::MAX as f64;
+    R::real(g.size() as f64 * (2.0 * t - 1.0))
+}
+
+fn scalar<A: Scalar>(g: &mut Gen, special: &[A::Real]) -> A {
+    let re = real(g, special);
+    let im = if A::IS_COMPLEX {
+        real(g, special)
+    } else {
+        A::Real::zero()
+    };
+    from_components(re, im)
+}
+
+/// Candidates towards zero: zero, the absolute value, the integer part and the half
+fn shrink_real<R: RealScalar>(x: R) -> Vec<R> {
+    let mut candidates = Vec::new();
+    if x == R::zero() {
+        return candidates;
+    }
+    candidates.push(R::zero());
+    if !Float::is_finite(x) {
+        return candidates;
+    }
+    let mut push = |y: R| {
+        if y != x && !candidates.contains(&y) {
+            candidates.push(y);
+        }
+    };
+    push(Float::abs(x));
+    push(Float::trunc(x));
+    push(x / R::real(2));
+    candidates
+}
+
+/// Candidates towards the real axis and then towards zero, component-wise
+fn shrink<A: Scalar>(x: A) -> impl Iterator<Item = A> {
+    let (re, im) = (x.re(), x.im());
+    let axis = if im != A::Real::zero() {
+        Some(from_components(re, A::Real::zero()))
+    } else {
+        None
+    };
+    let re_candidates = shrink_real(re)
+        .into_iter()
+        .map(move |re| from_components(re, im));
+    let im_candidates = shrink_real(im)
+        .into_iter()
+        .filter(|&im| im != A::Real::zero())
+        .map(move |im| from_components(re, im));
+    axis.into_iter().chain(re_candidates).chain(im_candidates)
+}
+
+impl<A: Scalar> Arbitrary for Any<A> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let special = [
+            A::Real::zero(),
+            -A::Real::zero(),
+            A::Real::infinity(),
+            A::Real::neg_infinity(),
+            A::Real::nan(),
+            A::Real::min_positive_value(),
+            A::Real::max_value(),
+            A::Real::min_value(),
+        ];
+        Any(scalar(g, &special))
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(shrink(self.0).map(Any))
+    }
+}
+
+impl<A: Scalar> Arbitrary for Finite<A> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let special = [
+            A::Real::zero(),
+            A::Real::min_positive_value(),
+            A::Real::max_value(),
+            A::Real::min_value(),
+        ];
+        Finite(scalar(g, &special))
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(shrink(self.0).map(Finite))
+    }
+}
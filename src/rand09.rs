@@ -0,0 +1,34 @@
+//! Adapter for random number generators of rand 0.9
+
+/// Use a random number generator implementing the `RngCore` of rand 0.9 wherever
+/// [rand::Rng] of rand 0.8 is expected, e.g. in [crate::Scalar::rand]
+///
+/// ```
+/// use cauchy::*;
+/// use rand_09::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = Rng09(StdRng::seed_from_u64(0));
+/// let z = c64::rand(&mut rng);
+/// assert!(0.0 <= z.re && z.re < 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng09<R>(pub R);
+
+impl<R: rand_core_09::RngCore> rand::RngCore for Rng09<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
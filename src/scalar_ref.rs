@@ -0,0 +1,348 @@
+//! Scalar trait without the `Copy` bound, for heap-backed arbitrary precision numbers
+//!
+//! [ScalarRef] covers the arithmetic core of [Scalar](crate::Scalar) with operands taken
+//! by reference where a copy would be needed, so that big-float and big-rational types can
+//! implement it. It is implemented for `f32`, `f64`, `c32` and `c64`.
+//! It is not re-exported at the crate root, since its methods share names with those of
+//! [Scalar](crate::Scalar) and would make calls like `x.re()` ambiguous under `use cauchy::*`.
+//!
+//! ```
+//! use cauchy::scalar_ref::ScalarRef;
+//! use num_traits::Zero;
+//!
+//! fn norm2<A: ScalarRef>(xs: &[A]) -> A::Real {
+//!     let mut sum = A::Real::zero();
+//!     for x in xs {
+//!         sum = sum + x.square();
+//!     }
+//!     ScalarRef::sqrt(&sum)
+//! }
+//!
+//! assert_eq!(norm2(&[3.0, 4.0]), 5.0);
+//! assert_eq!(norm2(&[cauchy::c64::new(3.0, 4.0)]), 5.0);
+//! ```
+//!
+//! Third-party float types implementing `num_traits::Float`, and complex numbers over them,
+//! implement it through [FloatAdapter]. For real types implementing [AdapterFloat], the
+//! adapter also implements [Scalar](crate::Scalar), though not
+//! [ScalarLayout](crate::ScalarLayout) since `Float` does not determine a memory layout.
+
+use crate::{c32, c64, Scalar, ScalarApprox, ScalarArchive};
+use core::fmt::{self, Debug, Display, LowerExp, UpperExp};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+use num_complex::ComplexFloat;
+use num_traits::{ConstOne, ConstZero, Float, FloatConst, NumCast, One, ToPrimitive, Zero};
+use serde::{Deserialize, Serialize};
+
+/// Arithmetic core of [Scalar](crate::Scalar) requiring only [Clone]
+pub trait ScalarRef:
+    Clone
+    + PartialEq
+    + Debug
+    + Display
+    + Zero
+    + One
+    + Neg<Output = Self>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + for<'a> Div<&'a Self, Output = Self>
+    + for<'a> AddAssign<&'a Self>
+    + for<'a> SubAssign<&'a Self>
+    + for<'a> MulAssign<&'a Self>
+    + for<'a> DivAssign<&'a Self>
+{
+    type Real: ScalarRef<Real = Self::Real> + PartialOrd;
+
+    /// Create a new real number, or `None` if `re` cannot be represented
+    fn try_real<T: ToPrimitive>(re: T) -> Option<Self::Real>;
+    /// Embed a real number
+    fn from_real(re: Self::Real) -> Self;
+    /// Real part
+    fn re(&self) -> Self::Real;
+    /// Imaginary part, zero for real numbers
+    fn im(&self) -> Self::Real;
+    /// Complex conjugate
+    fn conj(&self) -> Self;
+    /// Square of absolute value
+    fn square(&self) -> Self::Real;
+    /// Absolute value
+    fn abs(&self) -> Self::Real;
+    /// Principal square root
+    fn sqrt(&self) -> Self;
+    /// Multiply by a real number
+    fn mul_real(&self, re: &Self::Real) -> Self;
+    /// Divide by a real number
+    fn div_real(&self, re: &Self::Real) -> Self;
+}
+
+macro_rules! impl_scalar_ref {
+    ($t:ty) => {
+        impl ScalarRef for $t {
+            type Real = <$t as Scalar>::Real;
+
+            #[inline]
+            fn try_real<T: ToPrimitive>(re: T) -> Option<Self::Real> {
+                <$t as Scalar>::try_real(re)
+            }
+            #[inline]
+            fn from_real(re: Self::Real) -> Self {
+                <$t as Scalar>::from_real(re)
+            }
+            #[inline]
+            fn re(&self) -> Self::Real {
+                Scalar::re(self)
+            }
+            #[inline]
+            fn im(&self) -> Self::Real {
+                Scalar::im(self)
+            }
+            #[inline]
+            fn conj(&self) -> Self {
+                Scalar::conj(self)
+            }
+            #[inline]
+            fn square(&self) -> Self::Real {
+                Scalar::square(*self)
+            }
+            #[inline]
+            fn abs(&self) -> Self::Real {
+                Scalar::abs(*self)
+            }
+            #[inline]
+            fn sqrt(&self) -> Self {
+                Scalar::sqrt(*self)
+            }
+            #[inline]
+            fn mul_real(&self, re: &Self::Real) -> Self {
+                Scalar::mul_real(*self, *re)
+            }
+            #[inline]
+            fn div_real(&self, re: &Self::Real) -> Self {
+                Scalar::div_real(*self, *re)
+            }
+        }
+    };
+}
+
+impl_scalar_ref!(f32);
+impl_scalar_ref!(f64);
+impl_scalar_ref!(c32);
+impl_scalar_ref!(c64);
+
+/// Real float types for which [FloatAdapter] implements [Scalar](crate::Scalar)
+///
+/// Besides its supertraits, which follow the features of this crate as those of
+/// [NumScalar](crate::NumScalar), it provides the constants which [Scalar](crate::Scalar)
+/// has for const contexts and `Float` lacks. It is implemented for `f32` and `f64`, and
+/// crates defining float types implement it for them.
+pub trait AdapterFloat:
+    Float
+    + FloatConst
+    + ConstZero
+    + ConstOne
+    + FromStr
+    + Debug
+    + Display
+    + LowerExp
+    + UpperExp
+    + Serialize
+    + for<'de> Deserialize<'de>
+    + ScalarApprox<Self>
+    + ScalarArchive
+    + Send
+    + Sync
+    + 'static
+{
+    /// Archimedes' constant `pi`
+    const PI: Self;
+    /// Euler's number `e`
+    const E: Self;
+    /// Machine epsilon, the difference between 1 and the next larger number
+    const EPSILON: Self;
+}
+
+macro_rules! impl_adapter_consts {
+    ($real:ident) => {
+        impl AdapterFloat for $real {
+            const PI: Self = core::$real::consts::PI;
+            const E: Self = core::$real::consts::E;
+            const EPSILON: Self = $real::EPSILON;
+        }
+    };
+}
+
+impl_adapter_consts!(f32);
+impl_adapter_consts!(f64);
+
+/// Implementation of [ScalarRef] for any `num_complex::ComplexFloat`, that is any type
+/// implementing `num_traits::Float + FloatConst` and complex numbers over them
+///
+/// For real types implementing [AdapterFloat], `FloatAdapter<T>` also implements
+/// [Scalar](crate::Scalar) and [RealScalar](crate::RealScalar) with the complex type
+/// `Complex<FloatAdapter<T>>`, while `FloatAdapter<Complex<T>>` only implements [ScalarRef].
+/// Functions which `Float` lacks, such as `erf` and `gamma`, are evaluated by the series of
+/// this crate, and random numbers other than uniform ones are sampled as `f64` and rounded.
+/// The adapter is serialized transparently as the wrapped value.
+///
+/// ```
+/// use cauchy::scalar_ref::{FloatAdapter, ScalarRef};
+/// use num_complex::Complex;
+/// use num_traits::Zero;
+///
+/// fn norm2<A: ScalarRef>(xs: &[A]) -> A::Real {
+///     let sum = xs.iter().fold(A::Real::zero(), |sum, x| sum + x.square());
+///     ScalarRef::sqrt(&sum)
+/// }
+///
+/// // any type implementing `Float + FloatConst` in place of `f64`
+/// let xs = [FloatAdapter(Complex::new(3.0_f64, 4.0))];
+/// assert_eq!(norm2(&xs), FloatAdapter(5.0));
+/// ```
+///
+/// Generic code over [Scalar](crate::Scalar) takes the adapter of real types and complex
+/// numbers over it:
+///
+/// ```
+/// use cauchy::{scalar_ref::FloatAdapter, Scalar};
+/// use num_complex::Complex;
+///
+/// fn rms<A: Scalar>(xs: &[A]) -> A::Real {
+///     let sum: A::Real = xs.iter().map(|x| x.square()).sum();
+///     Scalar::sqrt(sum / A::real(xs.len()))
+/// }
+///
+/// let z = Complex::new(FloatAdapter(3.0_f32), FloatAdapter(4.0));
+/// assert_eq!(rms(&[z, -z]), FloatAdapter(5.0));
+/// assert_eq!(FloatAdapter::<f64>::parse("2.5"), Ok(FloatAdapter(2.5)));
+/// cauchy::test_suite::check_scalar::<Complex<FloatAdapter<f64>>>();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FloatAdapter<T>(pub T);
+
+impl<T: Display> Display for FloatAdapter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ComplexFloat> Neg for FloatAdapter<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        FloatAdapter(-self.0)
+    }
+}
+
+impl<T: ComplexFloat> Zero for FloatAdapter<T> {
+    #[inline]
+    fn zero() -> Self {
+        FloatAdapter(T::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: ComplexFloat> One for FloatAdapter<T> {
+    #[inline]
+    fn one() -> Self {
+        FloatAdapter(T::one())
+    }
+}
+
+macro_rules! impl_adapter_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<T: ComplexFloat> $op for FloatAdapter<T> {
+            type Output = Self;
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                FloatAdapter($op::$method(self.0, rhs.0))
+            }
+        }
+        impl<'a, T: ComplexFloat> $op<&'a Self> for FloatAdapter<T> {
+            type Output = Self;
+            #[inline]
+            fn $method(self, rhs: &'a Self) -> Self {
+                FloatAdapter($op::$method(self.0, rhs.0))
+            }
+        }
+        impl<'a, T: ComplexFloat> $op_assign<&'a Self> for FloatAdapter<T> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: &'a Self) {
+                self.0 = $op::$method(self.0, rhs.0);
+            }
+        }
+        impl<T: ComplexFloat> $op_assign for FloatAdapter<T> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                self.0 = $op::$method(self.0, rhs.0);
+            }
+        }
+    };
+}
+
+impl_adapter_op!(Add, add, AddAssign, add_assign);
+impl_adapter_op!(Sub, sub, SubAssign, sub_assign);
+impl_adapter_op!(Mul, mul, MulAssign, mul_assign);
+impl_adapter_op!(Div, div, DivAssign, div_assign);
+impl_adapter_op!(Rem, rem, RemAssign, rem_assign);
+
+impl<T> ScalarRef for FloatAdapter<T>
+where
+    T: ComplexFloat + From<T::Real> + Debug + Display,
+    T::Real: ComplexFloat<Real = T::Real> + From<T::Real> + Debug + Display,
+{
+    type Real = FloatAdapter<T::Real>;
+
+    #[inline]
+    fn try_real<P: ToPrimitive>(re: P) -> Option<Self::Real> {
+        NumCast::from(re).map(FloatAdapter)
+    }
+    #[inline]
+    fn from_real(re: Self::Real) -> Self {
+        FloatAdapter(<T as From<T::Real>>::from(re.0))
+    }
+    #[inline]
+    fn re(&self) -> Self::Real {
+        FloatAdapter(self.0.re())
+    }
+    #[inline]
+    fn im(&self) -> Self::Real {
+        FloatAdapter(self.0.im())
+    }
+    #[inline]
+    fn conj(&self) -> Self {
+        FloatAdapter(self.0.conj())
+    }
+    #[inline]
+    fn square(&self) -> Self::Real {
+        let (re, im) = (self.0.re(), self.0.im());
+        FloatAdapter(re * re + im * im)
+    }
+    #[inline]
+    fn abs(&self) -> Self::Real {
+        FloatAdapter(self.0.abs())
+    }
+    #[inline]
+    fn sqrt(&self) -> Self {
+        FloatAdapter(self.0.sqrt())
+    }
+    #[inline]
+    fn mul_real(&self, re: &Self::Real) -> Self {
+        FloatAdapter(self.0 * <T as From<T::Real>>::from(re.0))
+    }
+    #[inline]
+    fn div_real(&self, re: &Self::Real) -> Self {
+        FloatAdapter(self.0 / <T as From<T::Real>>::from(re.0))
+    }
+}
@@ -0,0 +1,35 @@
+//! Serialize scalars as strings like `"1.5+2.5i"`, for use with `#[serde(with = "cauchy::serde_string")]`
+//!
+//! ```
+//! use cauchy::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "cauchy::serde_string")]
+//!     gain: c64,
+//! }
+//!
+//! let json = serde_json::to_string(&Config { gain: c64::new(1.5, 2.5) }).unwrap();
+//! assert_eq!(json, r#"{"gain":"1.5+2.5i"}"#);
+//! let config: Config = serde_json::from_str(r#"{"gain": "-1 - 0.5i"}"#).unwrap();
+//! assert_eq!(config.gain, c64::new(-1.0, -0.5));
+//! ```
+
+use crate::Scalar;
+use core::fmt::Display;
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+/// Serialize a scalar as its [std::fmt::Display] string
+pub fn serialize<A: Scalar + Display, S: Serializer>(
+    x: &A,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(x)
+}
+
+/// Deserialize a scalar from any string accepted by [Scalar::parse]
+pub fn deserialize<'de, A: Scalar, D: Deserializer<'de>>(deserializer: D) -> Result<A, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    A::parse(&s).map_err(de::Error::custom)
+}
@@ -0,0 +1,75 @@
+//! Bridge to the SIMD traits of [simba](https://docs.rs/simba)
+//!
+//! Every scalar of this crate is a SIMD value of a single lane, named [SimbaScalar], and
+//! SIMD vectors of simba like `AutoF32x4` or `Complex<WideF64x4>` have [Scalar] lanes, named
+//! [ScalarLanes]. The vectors themselves cannot implement [Scalar], since their comparisons
+//! yield masks instead of `bool`. [pack] and [unpack] convert slices of scalars from and to
+//! the array-of-structures-of-arrays layout of such vectors.
+//!
+//! ```
+//! use cauchy::{c32, simba::{pack, unpack}, Scalar};
+//! use num_complex::Complex;
+//! use simba::simd::AutoF32x4;
+//!
+//! let xs: Vec<c32> = (0..6).map(|i| c32::new(i as f32, 1.0)).collect();
+//! let packed: Vec<_> = pack::<Complex<AutoF32x4>>(&xs).map(|z| z * z.conj()).collect();
+//! assert_eq!(packed.len(), 2);
+//!
+//! let mut ys = [c32::new(0.0, 0.0); 6];
+//! unpack(&packed, &mut ys);
+//! for (x, y) in xs.iter().zip(&ys) {
+//!     assert_eq!(*y, c32::from_real(x.square()));
+//! }
+//! ```
+
+use crate::Scalar;
+use ::simba::simd::{SimdComplexField, SimdValue};
+use num_traits::Zero;
+
+/// [Scalar] which is also a SIMD value of simba with a single lane
+pub trait SimbaScalar:
+    Scalar
+    + SimdComplexField<SimdRealField = <Self as Scalar>::Real>
+    + SimdValue<Element = Self, SimdBool = bool>
+{
+}
+
+impl<A> SimbaScalar for A where
+    A: Scalar
+        + SimdComplexField<SimdRealField = <A as Scalar>::Real>
+        + SimdValue<Element = A, SimdBool = bool>
+{
+}
+
+/// SIMD vector of simba whose lanes are scalars of type [ScalarLanes::Lane]
+pub trait ScalarLanes: SimdComplexField + SimdValue<Element = <Self as ScalarLanes>::Lane> {
+    type Lane: SimbaScalar;
+}
+
+impl<S> ScalarLanes for S
+where
+    S: SimdComplexField,
+    S::Element: SimbaScalar,
+{
+    type Lane = S::Element;
+}
+
+/// Pack consecutive scalars into the lanes of vectors, filling the last vector with zeros
+pub fn pack<S: ScalarLanes>(xs: &[S::Lane]) -> impl Iterator<Item = S> + '_ {
+    xs.chunks(S::LANES).map(|chunk| {
+        let mut v = S::splat(S::Lane::zero());
+        for (i, &x) in chunk.iter().enumerate() {
+            v.replace(i, x);
+        }
+        v
+    })
+}
+
+/// Unpack the lanes of vectors into consecutive scalars, until `out` or `packed` is exhausted
+pub fn unpack<S: ScalarLanes>(packed: &[S], out: &mut [S::Lane]) {
+    for (v, chunk) in packed.iter().zip(out.chunks_mut(S::LANES)) {
+        for (i, y) in chunk.iter_mut().enumerate() {
+            *y = v.extract(i);
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Elementwise math over slices, vectorized by the compiler
+//!
+//! The kernels are branch-free polynomial approximations in the style of fdlibm, accurate to
+//! about one ulp, which the compiler turns into SIMD instructions of the target. They profit
+//! from wider vector extensions, e.g. with `-C target-cpu=native`. `f32` is computed through
+//! the `f64` kernels. Arguments outside the range of the kernels, such as
+//! `|x| > 2^20 pi / 2` for `sin` and `cos`, fall back to the scalar implementation, as do
+//! the phases in complex `ln` and complex `sin` and `cos`.
+//!
+//! ```
+//! use cauchy::*;
+//!
+//! let mut xs = [0.0, 1.0, -2.5, 100.0];
+//! simd::exp(&mut xs);
+//! for (x, y) in xs.iter().zip(&[0.0_f64, 1.0, -2.5, 100.0]) {
+//!     assert!((x - y.exp()).abs() <= 2.0 * f64::EPSILON * x.abs());
+//! }
+//! ```
+
+use crate::Scalar;
+use num_complex::Complex;
+use num_traits::Float;
+
+/// Length of the chunks which are copied to be fixed up by the scalar fallback
+const CHUNK: usize = 64;
+
+/// Largest argument of the `sin` and `cos` kernels, for which three-part reduction is accurate
+const TRIG_LIMIT: f64 = 1.6470993291652855e6;
+
+/// `1.5 * 2^52`, whose ulp is one, so that adding it rounds to an integer in the low bits
+const MAGIC: f64 = 6755399441055744.0;
+
+/// Round to the nearest integer for `|x| < 2^51` without a library call
+#[inline(always)]
+fn round(x: f64) -> f64 {
+    (x + MAGIC) - MAGIC
+}
+
+/// Two's complement bits of an integer `|k| < 2^51` given as float,
+/// avoiding float-to-integer conversions which have no SIMD instructions on many targets
+#[inline(always)]
+fn int_bits(k: f64) -> u64 {
+    (k + MAGIC).to_bits().wrapping_sub(MAGIC.to_bits())
+}
+
+/// `2^k` for an integer `-1022 <= k <= 1023` given as float
+#[inline(always)]
+fn pow2(k: f64) -> f64 {
+    f64::from_bits(int_bits(k).wrapping_add(1023) << 52)
+}
+
+#[inline(always)]
+fn exp_kernel(x: f64) -> f64 {
+    const LN2_HI: f64 = 6.931471803691238e-1;
+    const LN2_LO: f64 = 1.9082149292705877e-10;
+    const P1: f64 = 1.6666666666666602e-1;
+    const P2: f64 = -2.7777777777015593e-3;
+    const P3: f64 = 6.613756321437934e-5;
+    const P4: f64 = -1.6533902205465252e-6;
+    const P5: f64 = 4.1381367970572385e-8;
+    const OVERFLOW: f64 = 709.782712893384;
+    const UNDERFLOW: f64 = -745.1332191019411;
+
+    let c = x.clamp(UNDERFLOW, OVERFLOW);
+    let k = round(c * core::f64::consts::LOG2_E);
+    let hi = c - k * LN2_HI;
+    let lo = k * LN2_LO;
+    let r = hi - lo;
+    let z = r * r;
+    let p = r - z * (P1 + z * (P2 + z * (P3 + z * (P4 + z * P5))));
+    let y = 1.0 - ((lo - (r * p) / (2.0 - p)) - hi);
+    // scale in two steps, so that subnormal and near-overflow results are representable
+    let k1 = round(0.5 * k);
+    let y = y * pow2(k1) * pow2(k - k1);
+    if x > OVERFLOW {
+        f64::INFINITY
+    } else if x < UNDERFLOW {
+        0.0
+    } else if x.is_nan() {
+        x
+    } else {
+        y
+    }
+}
+
+#[inline(always)]
+fn ln_kernel(x: f64) -> f64 {
+    const LN2_HI: f64 = 6.931471803691238e-1;
+    const LN2_LO: f64 = 1.9082149292705877e-10;
+    const LG1: f64 = 6.666666666666735e-1;
+    const LG2: f64 = 3.999999999940942e-1;
+    const LG3: f64 = 2.857142874366239e-1;
+    const LG4: f64 = 2.2222198432149784e-1;
+    const LG5: f64 = 1.818357216161805e-1;
+    const LG6: f64 = 1.5313837699209373e-1;
+    const LG7: f64 = 1.4798198605116586e-1;
+
+    // normalize subnormals
+    const TWO_54: f64 = 18014398509481984.0;
+    let subnormal = x < f64::MIN_POSITIVE;
+    let s = if subnormal { x * TWO_54 } else { x };
+    let bits = s.to_bits();
+    let exponent = f64::from_bits(MAGIC.to_bits() + ((bits >> 52) & 0x7ff)) - MAGIC;
+    let m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    let large = m > core::f64::consts::SQRT_2;
+    let m = if large { 0.5 * m } else { m };
+    let k =
+        exponent - if subnormal { 1023.0 + 54.0 } else { 1023.0 } + if large { 1.0 } else { 0.0 };
+
+    let f = m - 1.0;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    let hfsq = 0.5 * f * f;
+    let y = k * LN2_HI - ((hfsq - (s * (hfsq + t1 + t2) + k * LN2_LO)) - f);
+    if x == 0.0 {
+        f64::NEG_INFINITY
+    } else if x < 0.0 {
+        f64::NAN
+    } else if x == f64::INFINITY || x.is_nan() {
+        x
+    } else {
+        y
+    }
+}
+
+/// `(sin(x), cos(x))` for `|x| <= TRIG_LIMIT`
+#[inline(always)]
+fn sin_cos_kernel(x: f64) -> (f64, f64) {
+    const PIO2_1: f64 = 1.5707963267341256;
+    const PIO2_2: f64 = 6.077100506303966e-11;
+    const PIO2_3: f64 = 2.0222662487111665e-21;
+    const S1: f64 = -1.6666666666666632e-1;
+    const S2: f64 = 8.33333333332249e-3;
+    const S3: f64 = -1.984126982985795e-4;
+    const S4: f64 = 2.7557313707070068e-6;
+    const S5: f64 = -2.5050760253406863e-8;
+    const S6: f64 = 1.58969099521155e-10;
+    const C1: f64 = 4.16666666666666e-2;
+    const C2: f64 = -1.388888888887411e-3;
+    const C3: f64 = 2.480158728947673e-5;
+    const C4: f64 = -2.7557314351390663e-7;
+    const C5: f64 = 2.087572321298175e-9;
+    const C6: f64 = -1.1359647557788195e-11;
+
+    let n = round(x * core::f64::consts::FRAC_2_PI);
+    let r = ((x - n * PIO2_1) - n * PIO2_2) - n * PIO2_3;
+    let z = r * r;
+
+    let v = z * r;
+    let p = S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)));
+    let sin = r + v * (S1 + z * p);
+
+    let q = z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))));
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+    let cos = w + (((1.0 - w) - hz) + z * q);
+
+    // swap for odd quadrants, and flip the signs by the quadrant bits
+    let q = int_bits(n);
+    let swap = (q & 1).wrapping_neg();
+    let (sin, cos) = (sin.to_bits(), cos.to_bits());
+    let (s, c) = ((sin & !swap) | (cos & swap), (cos & !swap) | (sin & swap));
+    let s = s ^ ((q & 2) << 62);
+    let c = c ^ (((q + 1) & 2) << 62);
+    (f64::from_bits(s), f64::from_bits(c))
+}
+
+/// `sqrt(x^2 + y^2)` without overflow
+#[inline(always)]
+fn hypot_kernel(x: f64, y: f64) -> f64 {
+    let (x, y) = (x.abs(), y.abs());
+    let (big, small) = if x > y { (x, y) } else { (y, x) };
+    let r = small / big;
+    let h = big * Float::sqrt(1.0 + r * r);
+    if x == f64::INFINITY || y == f64::INFINITY {
+        f64::INFINITY
+    } else if big == 0.0 {
+        0.0
+    } else {
+        h
+    }
+}
+
+/// Apply a kernel over a slice of reals through `f64`
+#[inline(always)]
+fn map<T: Copy>(
+    xs: &mut [T],
+    to: impl Fn(T) -> f64,
+    from: impl Fn(f64) -> T,
+    kernel: impl Fn(f64) -> f64,
+) {
+    for x in xs.iter_mut() {
+        *x = from(kernel(to(*x)));
+    }
+}
+
+/// Apply the `sin` or `cos` kernel through `f64`, falling back to `scalar` for large arguments
+fn map_trig<T: Copy>(
+    xs: &mut [T],
+    to: impl Fn(T) -> f64,
+    from: impl Fn(f64) -> T,
+    select: impl Fn((f64, f64)) -> f64,
+    scalar: impl Fn(T) -> T,
+) {
+    for chunk in xs.chunks_mut(CHUNK) {
+        let mut input = [0.0; CHUNK];
+        let mut large = false;
+        for (x, i) in chunk.iter_mut().zip(input.iter_mut()) {
+            *i = to(*x);
+            large |= i.abs() > TRIG_LIMIT;
+            *x = from(select(sin_cos_kernel(*i)));
+        }
+        if large {
+            for (x, &i) in chunk.iter_mut().zip(input.iter()) {
+                if i.abs() > TRIG_LIMIT {
+                    *x = scalar(from(i));
+                }
+            }
+        }
+    }
+}
+
+/// Vectorized elementwise math, implemented for `f32`, `f64`, `c32` and `c64`
+pub trait SimdMath: Scalar {
+    /// Replace each element by its exponential, as [Scalar::exp]
+    fn exp_slice(xs: &mut [Self]);
+    /// Replace each element by its natural logarithm, as [Scalar::ln]
+    fn ln_slice(xs: &mut [Self]);
+    /// Replace each element by its sine, as [Scalar::sin]
+    fn sin_slice(xs: &mut [Self]);
+    /// Replace each element by its cosine, as [Scalar::cos]
+    fn cos_slice(xs: &mut [Self]);
+    /// Replace each element by its absolute value, as [Scalar::abs] with zero imaginary part
+    fn abs_slice(xs: &mut [Self]);
+}
+
+macro_rules! impl_simd_real {
+    ($real:ty) => {
+        impl SimdMath for $real {
+            fn exp_slice(xs: &mut [Self]) {
+                map(xs, |x| x as f64, |y| y as $real, exp_kernel);
+            }
+            fn ln_slice(xs: &mut [Self]) {
+                map(xs, |x| x as f64, |y| y as $real, ln_kernel);
+            }
+            fn sin_slice(xs: &mut [Self]) {
+                map_trig(xs, |x| x as f64, |y| y as $real, |sc| sc.0, Scalar::sin);
+            }
+            fn cos_slice(xs: &mut [Self]) {
+                map_trig(xs, |x| x as f64, |y| y as $real, |sc| sc.1, Scalar::cos);
+            }
+            fn abs_slice(xs: &mut [Self]) {
+                for x in xs.iter_mut() {
+                    *x = Scalar::abs(*x);
+                }
+            }
+        }
+    };
+}
+
+impl_simd_real!(f32);
+impl_simd_real!(f64);
+
+macro_rules! impl_simd_complex {
+    ($real:ty) => {
+        impl SimdMath for Complex<$real> {
+            fn exp_slice(xs: &mut [Self]) {
+                for chunk in xs.chunks_mut(CHUNK) {
+                    let mut input = [Complex::new(0.0, 0.0); CHUNK];
+                    let mut large = false;
+                    for (z, i) in chunk.iter_mut().zip(input.iter_mut()) {
+                        *i = *z;
+                        let (re, im) = (z.re as f64, z.im as f64);
+                        large |= im.abs() > TRIG_LIMIT;
+                        let r = exp_kernel(re);
+                        let (s, c) = sin_cos_kernel(im);
+                        // a real argument stays real, even if its exponential is infinite
+                        let im = if im == 0.0 { im } else { r * s };
+                        *z = Complex::new((r * c) as $real, im as $real);
+                    }
+                    if large {
+                        for (z, i) in chunk.iter_mut().zip(input.iter()) {
+                            if (i.im as f64).abs() > TRIG_LIMIT {
+                                *z = Scalar::exp(*i);
+                            }
+                        }
+                    }
+                }
+            }
+            fn ln_slice(xs: &mut [Self]) {
+                for z in xs.iter_mut() {
+                    let (re, im) = (z.re as f64, z.im as f64);
+                    let arg = Float::atan2(z.im, z.re);
+                    *z = Complex::new(ln_kernel(hypot_kernel(re, im)) as $real, arg);
+                }
+            }
+            fn sin_slice(xs: &mut [Self]) {
+                for z in xs.iter_mut() {
+                    *z = Scalar::sin(*z);
+                }
+            }
+            fn cos_slice(xs: &mut [Self]) {
+                for z in xs.iter_mut() {
+                    *z = Scalar::cos(*z);
+                }
+            }
+            fn abs_slice(xs: &mut [Self]) {
+                for z in xs.iter_mut() {
+                    *z = Complex::new(hypot_kernel(z.re as f64, z.im as f64) as $real, 0.0);
+                }
+            }
+        }
+    };
+}
+
+impl_simd_complex!(f32);
+impl_simd_complex!(f64);
+
+/// Replace each element by its exponential, see [SimdMath::exp_slice]
+pub fn exp<A: SimdMath>(xs: &mut [A]) {
+    A::exp_slice(xs)
+}
+
+/// Replace each element by its natural logarithm, see [SimdMath::ln_slice]
+pub fn ln<A: SimdMath>(xs: &mut [A]) {
+    A::ln_slice(xs)
+}
+
+/// Replace each element by its sine, see [SimdMath::sin_slice]
+pub fn sin<A: SimdMath>(xs: &mut [A]) {
+    A::sin_slice(xs)
+}
+
+/// Replace each element by its cosine, see [SimdMath::cos_slice]
+pub fn cos<A: SimdMath>(xs: &mut [A]) {
+    A::cos_slice(xs)
+}
+
+/// Replace each element by its absolute value, see [SimdMath::abs_slice]
+pub fn abs<A: SimdMath>(xs: &mut [A]) {
+    A::abs_slice(xs)
+}
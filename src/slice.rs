@@ -0,0 +1,159 @@
+//! Conversions between layouts of slices of scalars
+
+use crate::{sum::from_components, Scalar};
+use core::any::TypeId;
+use num_complex::Complex;
+
+/// Split complex numbers into planes of real and imaginary parts, the inverse of [merge_re_im]
+///
+/// Slices of `c32` and `c64` are split as plain loops over their interleaved components,
+/// which the compiler vectorizes. Panics if the lengths differ.
+///
+/// ```
+/// use cauchy::{c32, slice::{merge_re_im, split_re_im}};
+///
+/// let zs = [c32::new(1.0, 2.0), c32::new(3.0, 4.0)];
+/// let (mut re, mut im) = ([0.0; 2], [0.0; 2]);
+/// split_re_im::<f32>(&zs, &mut re, &mut im);
+/// assert_eq!((re, im), ([1.0, 3.0], [2.0, 4.0]));
+///
+/// let mut ws = [c32::new(0.0, 0.0); 2];
+/// merge_re_im::<f32>(&re, &im, &mut ws);
+/// assert_eq!(ws, zs);
+/// ```
+pub fn split_re_im<A: Scalar>(xs: &[A::Complex], re: &mut [A::Real], im: &mut [A::Real]) {
+    assert!(
+        xs.len() == re.len() && xs.len() == im.len(),
+        "split into planes of different lengths"
+    );
+    if let (Some(xs), Some(re), Some(im)) = (
+        components::<A::Complex, f32>(xs),
+        components_mut::<A::Real, f32>(re),
+        components_mut::<A::Real, f32>(im),
+    ) {
+        return deinterleave(xs, re, im);
+    }
+    if let (Some(xs), Some(re), Some(im)) = (
+        components::<A::Complex, f64>(xs),
+        components_mut::<A::Real, f64>(re),
+        components_mut::<A::Real, f64>(im),
+    ) {
+        return deinterleave(xs, re, im);
+    }
+    for ((x, r), i) in xs.iter().zip(re.iter_mut()).zip(im.iter_mut()) {
+        *r = x.re();
+        *i = x.im();
+    }
+}
+
+/// Merge planes of real and imaginary parts into complex numbers
+///
+/// Slices of `c32` and `c64` are merged as in [split_re_im]. Panics if the lengths differ.
+pub fn merge_re_im<A: Scalar>(re: &[A::Real], im: &[A::Real], xs: &mut [A::Complex]) {
+    assert!(
+        xs.len() == re.len() && xs.len() == im.len(),
+        "merge planes of different lengths"
+    );
+    if let (Some(re), Some(im), Some(xs)) = (
+        components::<A::Real, f32>(re),
+        components::<A::Real, f32>(im),
+        components_mut::<A::Complex, f32>(xs),
+    ) {
+        return interleave(re, im, xs);
+    }
+    if let (Some(re), Some(im), Some(xs)) = (
+        components::<A::Real, f64>(re),
+        components::<A::Real, f64>(im),
+        components_mut::<A::Complex, f64>(xs),
+    ) {
+        return interleave(re, im, xs);
+    }
+    for ((x, &r), &i) in xs.iter_mut().zip(re.iter()).zip(im.iter()) {
+        *x = from_components(r, i);
+    }
+}
+
+/// Copy pairs of components of `xs` into `re` and `im`
+fn deinterleave<T: Copy>(xs: &[T], re: &mut [T], im: &mut [T]) {
+    for ((x, r), i) in xs.chunks_exact(2).zip(re.iter_mut()).zip(im.iter_mut()) {
+        *r = x[0];
+        *i = x[1];
+    }
+}
+
+/// Copy `re` and `im` into pairs of components of `xs`
+fn interleave<T: Copy>(re: &[T], im: &[T], xs: &mut [T]) {
+    for ((x, &r), &i) in xs.chunks_exact_mut(2).zip(re.iter()).zip(im.iter()) {
+        x[0] = r;
+        x[1] = i;
+    }
+}
+
+/// View as a slice of `T` if `A` is `T` or `Complex<T>`, with the components interleaved
+fn components<A: Scalar, T: 'static>(xs: &[A]) -> Option<&[T]> {
+    let len = if TypeId::of::<A>() == TypeId::of::<T>() {
+        xs.len()
+    } else if TypeId::of::<A>() == TypeId::of::<Complex<T>>() {
+        2 * xs.len()
+    } else {
+        return None;
+    };
+    // SAFETY: `A` is `T`, or `Complex<T>` which is `repr(C)` with two fields of `T`
+    Some(unsafe { core::slice::from_raw_parts(xs.as_ptr() as *const T, len) })
+}
+
+/// Mutable version of [components]
+fn components_mut<A: Scalar, T: 'static>(xs: &mut [A]) -> Option<&mut [T]> {
+    let len = if TypeId::of::<A>() == TypeId::of::<T>() {
+        xs.len()
+    } else if TypeId::of::<A>() == TypeId::of::<Complex<T>>() {
+        2 * xs.len()
+    } else {
+        return None;
+    };
+    // SAFETY: as in `components`
+    Some(unsafe { core::slice::from_raw_parts_mut(xs.as_mut_ptr() as *mut T, len) })
+}
+
+/// Convert every element as [Scalar::cast_lossy], e.g. between `f32` and `f64`
+///
+/// Conversions between `f32` and `f64` and between `c32` and `c64` run
+/// as plain loops over the components, which the compiler vectorizes.
+/// Panics if the lengths differ.
+///
+/// ```
+/// use cauchy::{c32, c64, slice::convert_slice};
+///
+/// let xs = [c64::new(0.1, 1e300), c64::new(-2.0, 0.5)];
+/// let mut ys = [c32::new(0.0, 0.0); 2];
+/// convert_slice(&xs, &mut ys);
+/// assert_eq!(ys, [c32::new(0.1, f32::INFINITY), c32::new(-2.0, 0.5)]);
+///
+/// let mut re = [0.0_f32; 2];
+/// convert_slice(&xs, &mut re);
+/// assert_eq!(re, [0.1, -2.0]);
+/// ```
+pub fn convert_slice<A: Scalar, B: Scalar>(src: &[A], dst: &mut [B]) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "conversion between slices of different lengths"
+    );
+    if A::IS_COMPLEX == B::IS_COMPLEX {
+        if let (Some(s), Some(d)) = (components::<A, f32>(src), components_mut::<B, f64>(dst)) {
+            for (d, &s) in d.iter_mut().zip(s) {
+                *d = s as f64;
+            }
+            return;
+        }
+        if let (Some(s), Some(d)) = (components::<A, f64>(src), components_mut::<B, f32>(dst)) {
+            for (d, &s) in d.iter_mut().zip(s) {
+                *d = s as f32;
+            }
+            return;
+        }
+    }
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = s.cast_lossy();
+    }
+}
@@ -0,0 +1,270 @@
+//! Implementation of special functions for complex arguments
+
+use crate::Scalar;
+use num_complex::Complex;
+use num_traits::{Float, FloatConst, One, Zero};
+
+fn cast<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Maclaurin series of `erf`, used where it does not suffer from cancellation
+///
+/// The terms grow up to `n ≈ |z|^2` before they decrease, so that the number of terms
+/// grows with `|z|^2`, e.g. for large imaginary arguments.
+fn erf_series<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    let z2 = -z * z;
+    let mut term = z;
+    let mut sum = z;
+    let terms = 200 + 2 * z.norm_sqr().to_usize().unwrap_or(usize::MAX / 4);
+    for n in 1..terms {
+        term = term * z2 / cast::<T>(n as f64);
+        let t = term / cast::<T>((2 * n + 1) as f64);
+        sum = sum + t;
+        if t.norm() <= T::epsilon() * sum.norm() || !sum.norm().is_finite() {
+            break;
+        }
+    }
+    sum * T::FRAC_2_SQRT_PI()
+}
+
+/// Continued fraction of `erfc` by Laplace, valid for `Re(z) > 0`
+fn erfc_cf<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    let mut f = z;
+    for k in (1..=60).rev() {
+        f = z + f.inv() * cast::<T>(k as f64 * 0.5);
+    }
+    (-z * z).exp() / (f * T::PI().sqrt())
+}
+
+/// Whether `z` is close enough to the imaginary axis for the series
+fn use_series<T: Float>(z: Complex<T>) -> bool {
+    z.re.abs() < cast(1.5) || z.norm_sqr() < cast(4.0)
+}
+
+pub(crate) fn erf<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    if z.re < T::zero() {
+        -erf(-z)
+    } else if use_series(z) {
+        erf_series(z)
+    } else {
+        Complex::new(T::one(), T::zero()) - erfc_cf(z)
+    }
+}
+
+pub(crate) fn erfc<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    if z.re < T::zero() {
+        Complex::new(cast(2.0), T::zero()) - erfc(-z)
+    } else if use_series(z) {
+        Complex::new(T::one(), T::zero()) - erf_series(z)
+    } else {
+        erfc_cf(z)
+    }
+}
+
+/// Coefficients of the Lanczos approximation with `g = 7`, `n = 9`
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEF: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Returns `t = z + g + 1/2` and the Lanczos sum for `Γ(z + 1)`
+fn lanczos<T: Float>(z: Complex<T>) -> (Complex<T>, Complex<T>) {
+    let mut x = Complex::new(cast::<T>(LANCZOS_COEF[0]), T::zero());
+    for (i, &p) in LANCZOS_COEF.iter().enumerate().skip(1) {
+        x = x + (z + cast::<T>(i as f64)).inv() * cast::<T>(p);
+    }
+    (z + cast::<T>(LANCZOS_G + 0.5), x)
+}
+
+pub(crate) fn gamma<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    let one = Complex::new(T::one(), T::zero());
+    if z.re < cast(0.5) {
+        // reflection formula: Γ(z) Γ(1 - z) = π / sin(πz)
+        Complex::new(T::PI(), T::zero()) / ((z * T::PI()).sin() * gamma(one - z))
+    } else {
+        let z = z - one;
+        let (t, x) = lanczos(z);
+        t.powc(z + cast::<T>(0.5)) * (-t).exp() * x * (T::PI() + T::PI()).sqrt()
+    }
+}
+
+pub(crate) fn ln_gamma<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    let one = Complex::new(T::one(), T::zero());
+    if z.re < cast(0.5) {
+        // reflection formula, shifted onto the principal branch of log-gamma
+        let k = (z.re * cast(0.5) + cast(0.25)).floor();
+        let k = if z.im.is_sign_negative() { -k } else { k };
+        let branch = Complex::new(T::zero(), (T::PI() + T::PI()) * k);
+        Complex::new(T::PI().ln(), T::zero()) - (z * T::PI()).sin().ln() - ln_gamma(one - z)
+            + branch
+    } else {
+        let z = z - one;
+        let (t, x) = lanczos(z);
+        t.ln() * (z + cast::<T>(0.5)) - t + x.ln() + (T::PI() + T::PI()).sqrt().ln()
+    }
+}
+
+/// Bernoulli numbers `B_2, B_4, ..., B_16`
+const BERNOULLI: [f64; 8] = [
+    1.0 / 6.0,
+    -1.0 / 30.0,
+    1.0 / 42.0,
+    -1.0 / 30.0,
+    5.0 / 66.0,
+    -691.0 / 2730.0,
+    7.0 / 6.0,
+    -3617.0 / 510.0,
+];
+
+pub(crate) fn digamma<A: Scalar>(x: A) -> A {
+    let pi: A::Real = cast(core::f64::consts::PI);
+    if x.re() < A::Real::zero() {
+        // reflection formula: ψ(1 - x) - ψ(x) = π cot(πx)
+        let (s, c) = x.mul_real(pi).sin_cos();
+        return digamma(A::one() - x) - (c / s).mul_real(pi);
+    }
+    // recurrence ψ(x) = ψ(x + 1) - 1/x up to the asymptotic region
+    let mut x = x;
+    let mut acc = A::zero();
+    while x.re() < cast(10.0) {
+        acc -= A::one() / x;
+        x = x.add_real(A::Real::one());
+    }
+    // ψ(x) ~ ln(x) - 1/2x - Σ B_2k / (2k x^2k)
+    let x2 = A::one() / (x * x);
+    let mut xp = A::one();
+    let mut series = A::zero();
+    for (k, &b) in BERNOULLI.iter().enumerate() {
+        xp *= x2;
+        series += xp.mul_real(cast(b / (2 * k + 2) as f64));
+    }
+    acc + x.ln() - (A::one() / x).mul_real(cast(0.5)) - series
+}
+
+/// Maximal order of the reflection formula of [polygamma], beyond which `(n - 1)!` overflows
+/// even in `f64`
+const POLYGAMMA_MAX_REFLECTION: usize = 192;
+
+pub(crate) fn polygamma<A: Scalar>(x: A, n: u32) -> A {
+    if n == 0 {
+        return digamma(x);
+    }
+    if !Float::is_finite(x.re()) || !Float::is_finite(x.im()) {
+        return A::from_real(A::Real::nan());
+    }
+    let one = A::Real::one();
+    if x.re() < A::Real::zero() {
+        // reflection formula: ψ^(n)(x) = (-1)^n ψ^(n)(1 - x) - π^(n+1) P_n(cot(πx)), where
+        // d^n/dθ^n cot(θ) = P_n(cot(θ)), i.e. P_0(c) = c and P_(m+1)(c) = -(1 + c^2) P_m'(c)
+        let n = n as usize;
+        if n >= POLYGAMMA_MAX_REFLECTION {
+            return A::from_real(A::Real::nan());
+        }
+        let mut p = [A::Real::zero(); POLYGAMMA_MAX_REFLECTION + 2];
+        p[1] = one;
+        for m in 0..n {
+            // coefficients of P_m, of degree m + 1
+            let prev = p;
+            for j in 0..=m + 2 {
+                let down = if j > 0 {
+                    prev[j - 1] * cast((j - 1) as f64)
+                } else {
+                    A::Real::zero()
+                };
+                p[j] = -(prev[j + 1] * cast((j + 1) as f64) + down);
+            }
+        }
+        // cot has period 1, and subtracting the nearest integer is exact for large arguments
+        let pi: A::Real = cast(core::f64::consts::PI);
+        let (s, c) = x.sub_real(Float::round(x.re())).mul_real(pi).sin_cos();
+        let cot = c / s;
+        let mut poly = A::zero();
+        for &coef in p[..n + 2].iter().rev() {
+            poly = poly * cot + A::from_real(coef);
+        }
+        let reflected = polygamma(A::one() - x, n as u32);
+        let reflected = if n % 2 == 1 { -reflected } else { reflected };
+        return reflected - poly.mul_real(Float::powi(pi, n as i32 + 1));
+    }
+    let nr: A::Real = cast(n as f64);
+    // ψ^(n)(x) = (-1)^(n+1) (n-1)! f(x), where f(x) = n Σ_k 1 / (x + k)^(n+1)
+    let mut fact = one;
+    for k in 1..n {
+        fact *= cast(k as f64);
+    }
+    let sign = if n % 2 == 1 { fact } else { -fact };
+
+    // recurrence f(x) = f(x + 1) + n / x^(n+1) up to the asymptotic region
+    let mut x = x;
+    let mut acc = A::zero();
+    while x.re() < nr + cast(10.0) {
+        acc += x.powi(-(n as i32) - 1).mul_real(nr);
+        x = x.add_real(one);
+    }
+    // f(x) ~ 1/x^n + n/2x^(n+1) + Σ B_2k / (2k)! n (n+1) ... (n+2k-1) / x^(n+2k)
+    let xinv = A::one() / x;
+    let x2 = xinv * xinv;
+    let mut xp = xinv.powi(n as i32);
+    let mut series = xp + (xp * xinv).mul_real(nr * cast(0.5));
+    let mut coef = one;
+    for (k, &b) in BERNOULLI.iter().enumerate() {
+        let j: A::Real = cast((2 * k) as f64);
+        coef = coef * (nr + j) * (nr + j + one) / ((j + one) * (j + one + one));
+        xp *= x2;
+        series += xp.mul_real(coef * cast(b));
+    }
+    (acc + series).mul_real(sign)
+}
+
+/// `ln(1 + x)` without cancellation for small `x`
+pub(crate) fn ln_1p<A: Scalar>(x: A) -> A {
+    let u = A::one() + x;
+    if u == A::one() {
+        x
+    } else {
+        u.ln() * x / (u - A::one())
+    }
+}
+
+/// Correction term of the Stirling formula, `ln Γ(x) - (x - 1/2) ln(x) + x - ln(2π) / 2`
+fn stirling_correction<A: Scalar>(x: A) -> A {
+    let x2 = A::one() / (x * x);
+    let mut xp = A::one() / x;
+    let mut series = A::zero();
+    for (k, &b) in BERNOULLI.iter().enumerate() {
+        let k = (2 * k + 2) as f64;
+        series += xp.mul_real(cast(b / (k * (k - 1.0))));
+        xp *= x2;
+    }
+    series
+}
+
+pub(crate) fn ln_beta<A: Scalar>(a: A, b: A) -> A {
+    let (p, q) = if a.re() <= b.re() { (a, b) } else { (b, a) };
+    let large: A::Real = cast(10.0);
+    let pq = p + q;
+    if p.re() >= large {
+        // both large: collect the Stirling formulae to avoid cancellation
+        let corr = stirling_correction(p) + stirling_correction(q) - stirling_correction(pq);
+        (p.ln() - pq.ln()) * p.sub_real(cast(0.5))
+            + (q / pq).ln() * q
+            + q.ln().mul_real(cast(-0.5))
+            + corr
+            + A::from_real(cast(0.5 * Float::ln(2.0 * core::f64::consts::PI)))
+    } else if q.re() >= large {
+        // only q large: ln Γ(q) - ln Γ(p + q) via Stirling formulae
+        let corr = stirling_correction(q) - stirling_correction(pq);
+        p.ln_gamma() + corr + p - p * pq.ln() + q.sub_real(cast(0.5)) * ln_1p(-p / pq)
+    } else {
+        p.ln_gamma() + q.ln_gamma() - pq.ln_gamma()
+    }
+}
@@ -0,0 +1,169 @@
+//! Numerically stable reductions used in statistics
+
+use crate::Scalar;
+use num_traits::{Float, Zero};
+
+/// Largest real part in `xs`, ignoring NaN, or negative infinity for an empty slice
+fn max_re<A: Scalar>(xs: &[A]) -> A::Real {
+    xs.iter()
+        .fold(A::Real::neg_infinity(), |m, x| Float::max(m, x.re()))
+}
+
+/// Logarithm of the sum of exponentials `ln(sum_i exp(x_i))`
+///
+/// The real parts are shifted by their maximum before exponentiation,
+/// so that the sum neither overflows nor underflows entirely.
+/// The sum of an empty slice is zero, and its logarithm negative infinity.
+///
+/// ```
+/// use cauchy::stats::logsumexp;
+///
+/// let xs = [1000.0, 1000.0];
+/// assert_eq!(logsumexp(&xs), 1000.0 + 2.0_f64.ln());
+/// assert_eq!(xs.iter().map(|x| x.exp()).sum::<f64>().ln(), f64::INFINITY);
+/// assert_eq!(logsumexp::<f32>(&[]), f32::NEG_INFINITY);
+/// assert!(logsumexp(&[f64::NAN]).is_nan());
+/// ```
+pub fn logsumexp<A: Scalar>(xs: &[A]) -> A {
+    let m = max_re(xs);
+    if m.is_infinite() {
+        // no finite shift exists, and the result is `m` unless some element is NaN
+        let nan = xs.iter().any(|x| x.re().is_nan() || x.im().is_nan());
+        return A::from_real(if nan { A::Real::nan() } else { m });
+    }
+    let shift = A::from_real(m);
+    xs.iter().map(|&x| (x - shift).exp()).sum::<A>().ln() + shift
+}
+
+/// Replace `xs` by its softmax `exp(x_i) / sum_j exp(x_j)`
+///
+/// The real parts are shifted by their maximum before exponentiation as in [logsumexp].
+///
+/// ```
+/// use cauchy::stats::softmax_inplace;
+///
+/// let mut xs = [1000.0_f32, 1000.0, f32::NEG_INFINITY];
+/// softmax_inplace(&mut xs);
+/// assert_eq!(xs, [0.5, 0.5, 0.0]);
+/// ```
+pub fn softmax_inplace<A: Scalar>(xs: &mut [A]) {
+    let shift = A::from_real(max_re(xs));
+    let mut sum = A::zero();
+    for x in xs.iter_mut() {
+        *x = (*x - shift).exp();
+        sum += *x;
+    }
+    for x in xs.iter_mut() {
+        *x /= sum;
+    }
+}
+
+/// Streaming mean and variance with Welford's algorithm
+///
+/// Complex samples have a complex mean and the real variance `E|x - mean|^2`.
+/// Accumulators over disjoint samples are combined with [RunningStats::merge],
+/// e.g. after a parallel Monte Carlo run.
+///
+/// ```
+/// use cauchy::stats::RunningStats;
+///
+/// let mut stats = RunningStats::new();
+/// for x in [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0] {
+///     stats.push(x);
+/// }
+/// assert_eq!(stats.len(), 4);
+/// assert_eq!(stats.mean(), 1e9 + 10.0);
+/// assert_eq!(stats.variance(), 30.0);
+///
+/// let (mut left, mut right) = (RunningStats::new(), RunningStats::new());
+/// left.push(1e9 + 4.0);
+/// right.push(1e9 + 7.0);
+/// right.push(1e9 + 13.0);
+/// right.push(1e9 + 16.0);
+/// left.merge(&right);
+/// assert_eq!(left.mean(), stats.mean());
+/// assert_eq!(left.variance(), stats.variance());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunningStats<A: Scalar> {
+    len: usize,
+    mean: A,
+    /// Sum of squared deviations from the running mean
+    m2: A::Real,
+}
+
+impl<A: Scalar> Default for RunningStats<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Scalar> RunningStats<A> {
+    /// Accumulator without samples
+    pub fn new() -> Self {
+        RunningStats {
+            len: 0,
+            mean: A::zero(),
+            m2: A::Real::zero(),
+        }
+    }
+
+    /// Add a sample
+    pub fn push(&mut self, x: A) {
+        self.len += 1;
+        let delta = x - self.mean;
+        self.mean += delta.div_real(A::real(self.len));
+        self.m2 += delta.conj_mul(x - self.mean).re();
+    }
+
+    /// Combine with the statistics of another, disjoint set of samples
+    pub fn merge(&mut self, other: &Self) {
+        if other.len == 0 {
+            return;
+        }
+        if self.len == 0 {
+            *self = *other;
+            return;
+        }
+        let len = self.len + other.len;
+        let delta = other.mean - self.mean;
+        let weight = A::real(other.len) / A::real(len);
+        self.mean += delta.mul_real(weight);
+        self.m2 += other.m2 + delta.square() * A::real(self.len) * weight;
+        self.len = len;
+    }
+
+    /// Number of samples
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no samples have been added
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Sample mean, NaN without samples
+    pub fn mean(&self) -> A {
+        if self.len == 0 {
+            return A::from_real(A::Real::nan());
+        }
+        self.mean
+    }
+
+    /// Unbiased sample variance `sum_i |x_i - mean|^2 / (n - 1)`, NaN with fewer than two samples
+    pub fn variance(&self) -> A::Real {
+        if self.len < 2 {
+            return A::Real::nan();
+        }
+        self.m2 / A::real(self.len - 1)
+    }
+}
+
+impl<A: Scalar> Extend<A> for RunningStats<A> {
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Accurate summation of slices
+
+use crate::Scalar;
+use num_traits::Float;
+
+/// Neumaier's improved Kahan summation of real numbers
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Neumaier<T> {
+    sum: T,
+    comp: T,
+}
+
+impl<T: Float> Neumaier<T> {
+    pub(crate) fn new() -> Self {
+        Neumaier {
+            sum: T::zero(),
+            comp: T::zero(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, x: T) {
+        let t = self.sum + x;
+        // recover the low-order bits of the smaller operand lost in the rounding of `t`
+        if self.sum.abs() >= x.abs() {
+            self.comp = self.comp + ((self.sum - t) + x);
+        } else {
+            self.comp = self.comp + ((x - t) + self.sum);
+        }
+        self.sum = t;
+    }
+
+    pub(crate) fn value(&self) -> T {
+        self.sum + self.comp
+    }
+}
+
+/// Error-free transformation `a + b = s + e` where `s` is the rounded sum
+#[inline]
+pub(crate) fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Error-free transformation `a b = p + e` where `p` is the rounded product
+#[inline]
+pub(crate) fn two_prod<T: Float>(a: T, b: T) -> (T, T) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// Assemble a scalar from its components, dropping the imaginary part for real types
+pub(crate) fn from_components<A: Scalar>(re: A::Real, im: A::Real) -> A {
+    A::try_from_complex(A::from_parts((re, im))).unwrap_or_else(|| A::from_real(re))
+}
+
+/// Sum with Neumaier's compensated summation, component-wise for complex numbers
+///
+/// The error is bounded by about twice the machine epsilon times the sum of absolute values,
+/// independent of the length of `xs`, unlike the naive sum.
+///
+/// ```
+/// use cauchy::sum::sum_compensated;
+///
+/// let xs = [1.0, 1e100, 1.0, -1e100];
+/// assert_eq!(sum_compensated(&xs), 2.0);
+/// assert_eq!(xs.iter().sum::<f64>(), 0.0);
+/// ```
+pub fn sum_compensated<A: Scalar>(xs: &[A]) -> A {
+    let mut re = Neumaier::new();
+    let mut im = Neumaier::new();
+    for x in xs {
+        re.add(x.re());
+        im.add(x.im());
+    }
+    from_components(re.value(), im.value())
+}
+
+/// Length up to which [sum_pairwise] adds directly
+const PAIRWISE_BLOCK: usize = 128;
+
+/// Sum by recursively halving `xs` and adding the halves, component-wise for complex numbers
+///
+/// The error grows as `log(n)` rather than `n` for the naive sum, at nearly the same speed.
+///
+/// ```
+/// use cauchy::sum::sum_pairwise;
+///
+/// let xs = vec![0.1_f32; 1 << 20];
+/// let exact = 0.1_f64 as f32 as f64 * (1 << 20) as f64;
+/// assert!((sum_pairwise(&xs) as f64 - exact).abs() < 1e-6 * exact);
+/// assert!((xs.iter().sum::<f32>() as f64 - exact).abs() > 1e-3 * exact);
+/// ```
+pub fn sum_pairwise<A: Scalar>(xs: &[A]) -> A {
+    if xs.len() > PAIRWISE_BLOCK {
+        let (left, right) = xs.split_at(xs.len() / 2);
+        return sum_pairwise(left) + sum_pairwise(right);
+    }
+    // independent partial sums vectorize, and are themselves added pairwise
+    let mut acc = [A::zero(); 8];
+    let chunks = xs.chunks_exact(acc.len());
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (a, &x) in acc.iter_mut().zip(chunk) {
+            *a += x;
+        }
+    }
+    let acc = ((acc[0] + acc[1]) + (acc[2] + acc[3])) + ((acc[4] + acc[5]) + (acc[6] + acc[7]));
+    rest.iter().fold(acc, |acc, &x| acc + x)
+}
+
+/// Replace `xs` by its prefix sums `x_0 + ... + x_i` with Neumaier's compensated summation
+///
+/// As in [sum_compensated], the error of every prefix sum is bounded independently of `i`.
+///
+/// ```
+/// use cauchy::sum::cumsum_inplace;
+///
+/// let mut xs = vec![0.1_f32; 1 << 16];
+/// let naive = xs.iter().sum::<f32>() as f64;
+/// cumsum_inplace(&mut xs);
+/// let exact = 0.1_f32 as f64 * (1 << 16) as f64;
+/// assert!((xs[xs.len() - 1] as f64 - exact).abs() < 1e-6 * exact);
+/// assert!((naive - exact).abs() > 1e-4 * exact);
+/// ```
+pub fn cumsum_inplace<A: Scalar>(xs: &mut [A]) {
+    let mut re = Neumaier::new();
+    let mut im = Neumaier::new();
+    for x in xs.iter_mut() {
+        re.add(x.re());
+        im.add(x.im());
+        *x = from_components(re.value(), im.value());
+    }
+}
+
+/// Replace `xs` by its prefix products `x_0 ... x_i`
+///
+/// The running product is kept as a mantissa and a separate binary exponent,
+/// so that a prefix product is finite whenever it is representable,
+/// even if an earlier one overflowed or underflowed. See [cumprod_ln_inplace] for
+/// products whose magnitude is out of range.
+///
+/// ```
+/// use cauchy::sum::cumprod_inplace;
+///
+/// let mut xs = [1e200, 1e200, 1e-300, 0.5];
+/// cumprod_inplace(&mut xs);
+/// assert_eq!(xs[1], f64::INFINITY);
+/// assert!((xs[2] / 1e100 - 1.0).abs() < 1e-15);
+/// assert!((xs[3] / 5e99 - 1.0).abs() < 1e-15);
+/// ```
+pub fn cumprod_inplace<A: Scalar>(xs: &mut [A]) {
+    let mut mantissa = A::one();
+    let mut exp = 0_i32;
+    for x in xs.iter_mut() {
+        let (m, e) = x.frexp();
+        let (m, e2) = (mantissa * m).frexp();
+        mantissa = m;
+        exp = exp.saturating_add(e).saturating_add(e2);
+        *x = mantissa.ldexp(exp);
+    }
+}
+
+/// Replace `xs` by the logarithms of its prefix products `ln(x_0 ... x_i)`,
+/// as the compensated prefix sums of `ln(x_i)`
+///
+/// For complex numbers the imaginary part is the sum of the principal arguments,
+/// which is a valid but not necessarily principal argument of the product.
+///
+/// ```
+/// use cauchy::sum::cumprod_ln_inplace;
+///
+/// let mut xs = [1e300, 1e300, 1e-300];
+/// cumprod_ln_inplace(&mut xs);
+/// assert!((xs[1] - 600.0 * 10_f64.ln()).abs() < 1e-12);
+/// ```
+pub fn cumprod_ln_inplace<A: Scalar>(xs: &mut [A]) {
+    for x in xs.iter_mut() {
+        *x = x.ln();
+    }
+    cumsum_inplace(xs);
+}
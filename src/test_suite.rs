@@ -0,0 +1,654 @@
+//! Conformance checks for implementations of [Scalar]
+//!
+//! The checks panic with a description of the violated property, so that they can be called
+//! from the tests of crates implementing [Scalar] for their own types. The serde
+//! implementations are checked through a minimal binary format within this module, and
+//! [check_roundtrip] checks them with any other format.
+//!
+//! ```
+//! use cauchy::*;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Meter(f64);
+//! impl_scalar_newtype!(Meter, f64);
+//!
+//! assert_scalar_conformance!(f32);
+//! assert_scalar_conformance!(c64);
+//! assert_scalar_conformance!(Meter);
+//!
+//! // round trip through a serialization format without infinities and NaN
+//! test_suite::check_roundtrip::<c32>(false, |z| {
+//!     serde_json::from_str(&serde_json::to_string(&z).unwrap()).unwrap()
+//! });
+//! ```
+
+use crate::{sum::from_components, Scalar, ScalarLayout};
+use core::fmt;
+use num_traits::{Float, One, Zero};
+use serde::{de, ser, Deserialize, Serialize};
+
+/// Run every check of this module on the type `A: ScalarLayout`
+#[macro_export]
+macro_rules! assert_scalar_conformance {
+    ($t:ty) => {{
+        $crate::test_suite::check_scalar::<$t>();
+        $crate::test_suite::check_layout::<$t>();
+    }};
+}
+
+/// Run every check of this module except [check_roundtrip] and [check_layout] on `A`
+pub fn check_scalar<A: Scalar>() {
+    check_kind::<A>();
+    check_identities::<A>();
+    check_conjugation::<A>();
+    check_polar::<A>();
+    check_conversions::<A>();
+    check_edge_cases::<A>();
+    check_functions::<A>();
+    check_serde::<A>();
+}
+
+/// Finite sample values, with non-zero imaginary parts for complex types
+pub fn samples<A: Scalar>() -> impl Iterator<Item = A> {
+    const PARTS: [(f64, f64); 8] = [
+        (0.5, 0.25),
+        (-1.5, 2.0),
+        (2.0, -3.0),
+        (3.25, 0.0),
+        (-7.0, -0.5),
+        (1e-3, 4.0),
+        (1e3, -1e-3),
+        (1.0, 1.0),
+    ];
+    PARTS
+        .iter()
+        .map(|&(re, im)| from_components(A::real(re), A::real(im)))
+}
+
+/// Sample values including zeros of both signs, infinities and NaN
+pub fn edge_samples<A: Scalar>() -> impl Iterator<Item = A> {
+    let special = [
+        A::Real::zero(),
+        -A::Real::zero(),
+        A::Real::infinity(),
+        A::Real::neg_infinity(),
+        A::Real::nan(),
+        A::Real::min_positive_value(),
+        A::Real::max_value(),
+    ];
+    let mut values = [A::zero(); 14];
+    for (i, &x) in special.iter().enumerate() {
+        values[2 * i] = A::from_real(x);
+        values[2 * i + 1] = from_components(A::Real::one(), x);
+    }
+    samples().chain(IntoIterator::into_iter(values))
+}
+
+/// Equality of the components, so that NaN equals itself and zeros are distinguished
+fn same<A: Scalar>(a: A, b: A) -> bool {
+    let same_real = |x: A::Real, y: A::Real| {
+        (x.is_nan() && y.is_nan()) || (x == y && x.is_sign_negative() == y.is_sign_negative())
+    };
+    same_real(a.re(), b.re()) && same_real(a.im(), b.im())
+}
+
+/// Relative closeness `|a - b| <= tol max(|a|, |b|)`
+fn close<A: Scalar>(a: A, b: A) -> bool {
+    let tol = A::real(64) * A::EPSILON;
+    Scalar::abs(a - b) <= tol * Float::max(Scalar::abs(a), Scalar::abs(b))
+}
+
+fn is_nan<A: Scalar>(x: A) -> bool {
+    Float::is_nan(x.re()) || Float::is_nan(x.im())
+}
+
+/// [Scalar::IS_COMPLEX] and [Scalar::COMPONENTS] agree with the values of `A`
+pub fn check_kind<A: Scalar>() {
+    let complex = A::try_from_complex(A::i()).is_some();
+    assert_eq!(
+        A::IS_COMPLEX,
+        complex,
+        "IS_COMPLEX disagrees with try_from_complex(i)"
+    );
+    assert_eq!(
+        A::COMPONENTS,
+        1 + complex as usize,
+        "COMPONENTS disagrees with IS_COMPLEX"
+    );
+    assert!(A::I == A::i(), "I differs from i()");
+    if !complex {
+        for x in samples::<A>() {
+            assert!(
+                x.im() == A::Real::zero(),
+                "real {:?} has an imaginary part",
+                x
+            );
+        }
+    }
+}
+
+/// Exact algebraic identities of the field operations
+#[allow(clippy::eq_op)]
+pub fn check_identities<A: Scalar>() {
+    for x in samples::<A>() {
+        assert!(x + A::zero() == x, "{:?} + 0 != {:?}", x, x);
+        assert!(x * A::one() == x, "{:?} * 1 != {:?}", x, x);
+        assert!(x / A::one() == x, "{:?} / 1 != {:?}", x, x);
+        assert!(x - x == A::zero(), "{:?} - {:?} != 0", x, x);
+        assert!(-(-x) == x, "-(-{:?}) != {:?}", x, x);
+        assert!(
+            A::ZERO == A::zero() && A::ONE == A::one(),
+            "ZERO or ONE differ"
+        );
+        for y in samples::<A>() {
+            assert!(
+                x + y == y + x,
+                "addition of {:?} and {:?} not commutative",
+                x,
+                y
+            );
+            assert!(
+                x * y == y * x,
+                "multiplication of {:?} and {:?} not commutative",
+                x,
+                y
+            );
+            assert!(
+                close((x * y) / y, x),
+                "({:?} * {:?}) / {:?} != {:?}",
+                x,
+                y,
+                y,
+                x
+            );
+        }
+    }
+}
+
+/// Properties of the complex conjugate and the absolute value
+pub fn check_conjugation<A: Scalar>() {
+    for x in samples::<A>() {
+        let c = x.conj();
+        assert!(c.conj() == x, "conj(conj({:?})) != {:?}", x, x);
+        assert!(
+            c.re() == x.re() && c.im() == -x.im(),
+            "conj({:?}) = {:?}",
+            x,
+            c
+        );
+        assert!(
+            close(x * c, A::from_real(x.square())),
+            "{:?} conj({:?}) != |{:?}|^2",
+            x,
+            x,
+            x
+        );
+        assert!(
+            close(A::from_real(x.abs() * x.abs()), A::from_real(x.square())),
+            "abs and square of {:?} disagree",
+            x
+        );
+        assert!(x.conj_mul(x) == c * x, "conj_mul({:?}) != conj(x) x", x);
+    }
+}
+
+/// Round trip through the polar form
+pub fn check_polar<A: Scalar>() {
+    for x in samples::<A>() {
+        let theta = Float::atan2(x.im(), x.re());
+        let z = A::from_polar(x.abs(), theta);
+        assert!(close(z, x.as_c()), "from_polar(|{:?}|, arg) = {:?}", x, z);
+        assert!(
+            close(A::cis(theta).mul_real(x.abs()), z),
+            "cis disagrees with from_polar"
+        );
+    }
+}
+
+/// Lossless conversions between representations of `A`
+pub fn check_conversions<A: Scalar>() {
+    for x in edge_samples::<A>() {
+        let parts = x.into_parts();
+        assert!(
+            same(A::Complex::from_parts(parts), x.as_c()),
+            "parts round trip of {:?}",
+            x
+        );
+        let back = A::try_from_complex(x.as_c()).expect("as_c must convert back");
+        assert!(same(back, x), "as_c round trip of {:?}", x);
+        if !is_nan(x) {
+            assert!(A::narrow(x.widen()) == x, "widen round trip of {:?}", x);
+        }
+    }
+}
+
+/// Lossless round trips through the bits and bytes of `A`, and slice views agreeing with
+/// [Scalar::re] and [Scalar::im]
+pub fn check_layout<A: ScalarLayout>() {
+    for x in edge_samples::<A>() {
+        let bits = x.to_bits();
+        assert!(
+            A::from_bits(bits).to_bits() == bits,
+            "bits round trip of {:?}",
+            x
+        );
+        assert!(
+            A::from_le_bytes(x.to_le_bytes()).to_bits() == bits,
+            "LE bytes round trip of {:?}",
+            x
+        );
+        assert!(
+            A::from_be_bytes(x.to_be_bytes()).to_bits() == bits,
+            "BE bytes round trip of {:?}",
+            x
+        );
+        let z = [x.as_c()];
+        let parts = A::complex_as_real_slice(&z);
+        assert!(
+            same(parts[0], x.re()) && same(parts[1], x.im()),
+            "complex_as_real_slice of {:?}",
+            x
+        );
+        assert!(
+            A::real_as_complex_slice(parts).is_some_and(|back| same(back[0], z[0])),
+            "real_as_complex_slice of {:?}",
+            x
+        );
+    }
+}
+
+/// Arithmetic with zeros of both signs, infinities and NaN
+#[allow(clippy::eq_op)]
+pub fn check_edge_cases<A: Scalar>() {
+    let zero = A::Real::zero();
+    let inf = A::Real::infinity();
+    let nan = A::from_real(A::Real::nan());
+    assert!(nan != nan, "NaN equals itself");
+    assert!(is_nan(nan + A::one()), "NaN + 1 is not NaN");
+    assert!(Float::is_nan(nan.abs()), "|NaN| is not NaN");
+    assert!(A::from_real(zero) == A::from_real(-zero), "0 != -0");
+    assert!(
+        Float::is_sign_negative((-A::from_real(zero)).re()),
+        "-0 lost its sign under negation"
+    );
+    assert!(A::from_real(-zero).abs() == zero, "|-0| != 0");
+    assert!(
+        Float::is_sign_positive(A::from_real(-zero).abs()),
+        "|-0| is negative"
+    );
+    assert!(A::from_real(inf).abs() == inf, "|inf| != inf");
+    assert!(A::from_real(-inf).abs() == inf, "|-inf| != inf");
+    assert!((A::from_real(inf) + A::one()).re() == inf, "inf + 1 != inf");
+    for x in samples::<A>() {
+        assert!(x.div_real(inf).abs() == zero, "{:?} / inf is not zero", x);
+    }
+    if !A::IS_COMPLEX {
+        let sqrt = A::from_real(-zero).sqrt();
+        assert!(
+            Float::is_sign_negative(sqrt.re()) && sqrt.re() == zero,
+            "sqrt(-0) != -0"
+        );
+        assert!(
+            A::from_real(-A::Real::one()).checked_sqrt().is_none(),
+            "sqrt(-1) is real"
+        );
+    }
+}
+
+/// Inverse pairs of elementary functions
+pub fn check_functions<A: Scalar>() {
+    for x in samples::<A>() {
+        if A::IS_COMPLEX || x.re() > A::Real::zero() {
+            let s = x.sqrt();
+            assert!(close(s * s, x), "sqrt({:?})^2 != {:?}", x, x);
+            assert!(close(x.ln().exp(), x), "exp(ln({:?})) != {:?}", x, x);
+            assert!(close(x.powi(2), x * x), "powi({:?}, 2) != x x", x);
+        }
+        let (sin, cos) = x.sin_cos();
+        assert!(
+            close(sin, x.sin()) && close(cos, x.cos()),
+            "sin_cos({:?}) disagrees",
+            x
+        );
+    }
+}
+
+/// Round trip through `f`, e.g. serialization and deserialization, with infinite and NaN
+/// samples only if `non_finite` since many formats cannot express them
+pub fn check_roundtrip<A: Scalar>(non_finite: bool, f: impl Fn(A) -> A) {
+    for x in edge_samples::<A>() {
+        let finite = Float::is_finite(x.re()) && Float::is_finite(x.im());
+        if is_nan(x) {
+            if non_finite {
+                assert!(is_nan(f(x)), "{:?} did not round trip to NaN", x);
+            }
+        } else if finite || non_finite {
+            let y = f(x);
+            assert!(same(y, x), "round trip of {:?} gives {:?}", x, y);
+        }
+    }
+}
+
+/// Round trip through the `Serialize` and `Deserialize` implementations of `A`, with a
+/// binary format of the serde data model which keeps infinities and NaN exactly
+///
+/// The format covers booleans, numbers, tuples, sequences, newtypes and structs, which suffice
+/// for scalars, and fails the check for other parts of the data model such as strings.
+pub fn check_serde<A: Scalar>() {
+    check_roundtrip::<A>(true, |x| {
+        let mut recorder = Recorder {
+            tokens: [Token::Bool(false); MAX_TOKENS],
+            len: 0,
+        };
+        if let Err(e) = Serialize::serialize(&x, &mut recorder) {
+            panic!("serialization of {:?} failed: {}", x, e);
+        }
+        let mut replay = Replay {
+            tokens: &recorder.tokens[..recorder.len],
+        };
+        let y = <A as Deserialize>::deserialize(&mut replay)
+            .unwrap_or_else(|e| panic!("deserialization of {:?} failed: {}", x, e));
+        assert!(
+            replay.tokens.is_empty(),
+            "deserialization of {:?} left tokens",
+            x
+        );
+        y
+    });
+}
+
+/// Capacity of [Recorder], far more than any scalar needs
+const MAX_TOKENS: usize = 32;
+
+/// Values and headers of compounds of the serde data model
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    /// Header of a tuple, tuple struct, sequence or struct of this many elements
+    Seq(usize),
+    /// Header of a newtype struct
+    Newtype,
+}
+
+#[derive(Debug)]
+struct TokenError(&'static str);
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl ser::StdError for TokenError {}
+
+impl ser::Error for TokenError {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        TokenError("custom error of Serialize")
+    }
+}
+
+impl de::Error for TokenError {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        TokenError("custom error of Deserialize")
+    }
+}
+
+/// Serializer into a fixed buffer of tokens
+struct Recorder {
+    tokens: [Token; MAX_TOKENS],
+    len: usize,
+}
+
+impl Recorder {
+    fn push(&mut self, token: Token) -> Result<(), TokenError> {
+        let slot = self
+            .tokens
+            .get_mut(self.len)
+            .ok_or(TokenError("too many tokens"))?;
+        *slot = token;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+const UNSUPPORTED: TokenError = TokenError("unsupported part of the serde data model");
+
+macro_rules! record {
+    ($($method:ident: $t:ty => $token:ident as $u:ty),*) => {
+        $(
+            fn $method(self, v: $t) -> Result<(), TokenError> {
+                self.push(Token::$token(v as $u))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for &mut Recorder {
+    type Ok = ();
+    type Error = TokenError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = ser::Impossible<(), TokenError>;
+    type SerializeMap = ser::Impossible<(), TokenError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = ser::Impossible<(), TokenError>;
+
+    record!(
+        serialize_bool: bool => Bool as bool,
+        serialize_i8: i8 => I64 as i64,
+        serialize_i16: i16 => I64 as i64,
+        serialize_i32: i32 => I64 as i64,
+        serialize_i64: i64 => I64 as i64,
+        serialize_u8: u8 => U64 as u64,
+        serialize_u16: u16 => U64 as u64,
+        serialize_u32: u32 => U64 as u64,
+        serialize_u64: u64 => U64 as u64,
+        serialize_f32: f32 => F32 as f32,
+        serialize_f64: f64 => F64 as f64
+    );
+
+    fn serialize_char(self, _: char) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_str(self, _: &str) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn collect_str<T: ?Sized + fmt::Display>(self, _: &T) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_none(self) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_unit(self) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), TokenError> {
+        self.push(Token::Newtype)?;
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self, TokenError> {
+        self.push(Token::Seq(len.ok_or(UNSUPPORTED)?))?;
+        Ok(self)
+    }
+    fn serialize_tuple(self, len: usize) -> Result<Self, TokenError> {
+        self.push(Token::Seq(len))?;
+        Ok(self)
+    }
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<Self, TokenError> {
+        self.push(Token::Seq(len))?;
+        Ok(self)
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, TokenError> {
+        Err(UNSUPPORTED)
+    }
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<Self, TokenError> {
+        self.push(Token::Seq(len))?;
+        Ok(self)
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, TokenError> {
+        Err(UNSUPPORTED)
+    }
+}
+
+macro_rules! record_elements {
+    ($($trait:ident, $method:ident;)*) => {
+        $(
+            impl ser::$trait for &mut Recorder {
+                type Ok = ();
+                type Error = TokenError;
+                fn $method<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), TokenError> {
+                    value.serialize(&mut **self)
+                }
+                fn end(self) -> Result<(), TokenError> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+record_elements!(
+    SerializeSeq, serialize_element;
+    SerializeTuple, serialize_element;
+    SerializeTupleStruct, serialize_field;
+);
+
+impl ser::SerializeStruct for &mut Recorder {
+    type Ok = ();
+    type Error = TokenError;
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), TokenError> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), TokenError> {
+        Ok(())
+    }
+}
+
+/// Deserializer from the tokens of a [Recorder], visiting structs as sequences
+struct Replay<'t> {
+    tokens: &'t [Token],
+}
+
+impl<'t> Replay<'t> {
+    fn next(&mut self) -> Result<Token, TokenError> {
+        let (&token, rest) = self
+            .tokens
+            .split_first()
+            .ok_or(TokenError("end of tokens"))?;
+        self.tokens = rest;
+        Ok(token)
+    }
+}
+
+impl<'de, 'a, 't> de::Deserializer<'de> for &'a mut Replay<'t> {
+    type Error = TokenError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, TokenError> {
+        match self.next()? {
+            Token::Bool(v) => visitor.visit_bool(v),
+            Token::I64(v) => visitor.visit_i64(v),
+            Token::U64(v) => visitor.visit_u64(v),
+            Token::F32(v) => visitor.visit_f32(v),
+            Token::F64(v) => visitor.visit_f64(v),
+            Token::Seq(len) => visitor.visit_seq(Elements { de: self, len }),
+            Token::Newtype => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    // newtypes may also be serialized transparently, as by `impl_scalar_newtype`
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TokenError> {
+        if self.tokens.first() == Some(&Token::Newtype) {
+            self.next()?;
+        }
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+/// Remaining elements of a sequence
+struct Elements<'a, 't> {
+    de: &'a mut Replay<'t>,
+    len: usize,
+}
+
+impl<'de, 'a, 't> de::SeqAccess<'de> for Elements<'a, 't> {
+    type Error = TokenError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, TokenError> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
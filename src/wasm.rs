@@ -0,0 +1,79 @@
+//! Conversions to and from JavaScript values with
+//! [wasm-bindgen](https://docs.rs/wasm-bindgen) and [js-sys](https://docs.rs/js-sys)
+//!
+//! Real numbers are JavaScript numbers, and complex numbers are arrays `[re, im]` of two
+//! numbers. Slices are `Float64Array`s, with the components of complex numbers interleaved
+//! as `[re0, im0, re1, im1, ...]`. Numbers from JavaScript are rounded to the precision of
+//! the scalar type.
+//!
+//! The functions can only be called on `wasm32` targets.
+//!
+//! ```no_run
+//! use cauchy::{c64, wasm::{from_float64_array, from_js, to_float64_array, to_js}};
+//!
+//! let z = c64::new(1.0, -2.0);
+//! assert_eq!(from_js::<c64>(&to_js(z)), Some(z));
+//! // a number is a complex number with zero imaginary part, but `[re, im]` is no real number
+//! assert_eq!(from_js::<c64>(&to_js(3.0_f64)), Some(c64::new(3.0, 0.0)));
+//! assert_eq!(from_js::<f64>(&to_js(z)), None);
+//!
+//! let array = to_float64_array(&[z, z.conj()]);
+//! assert_eq!(array.to_vec(), vec![1.0, -2.0, 1.0, 2.0]);
+//! assert_eq!(from_float64_array::<c64>(&array), Some(vec![z, z.conj()]));
+//! ```
+
+use crate::{c64, Scalar};
+use ::js_sys::{Array, Float64Array};
+use ::wasm_bindgen::{JsCast, JsValue};
+
+/// Convert into a number, or an array `[re, im]` for complex types
+pub fn to_js<A: Scalar>(x: A) -> JsValue {
+    let z: c64 = x.cast_lossy();
+    if A::IS_COMPLEX {
+        Array::of2(&JsValue::from_f64(z.re), &JsValue::from_f64(z.im)).into()
+    } else {
+        JsValue::from_f64(z.re)
+    }
+}
+
+/// Convert from a number or an array `[re, im]` of two numbers,
+/// or `None` if the value is neither or has a non-zero imaginary part for real types
+pub fn from_js<A: Scalar>(value: &JsValue) -> Option<A> {
+    let (re, im) = match value.as_f64() {
+        Some(re) => (re, 0.0),
+        None => match value.dyn_ref::<Array>()?.to_vec().as_slice() {
+            [re, im] => (re.as_f64()?, im.as_f64()?),
+            _ => return None,
+        },
+    };
+    A::try_from_complex(A::complex(re, im))
+}
+
+/// Copy into a new `Float64Array`, interleaving the components of complex numbers
+pub fn to_float64_array<A: Scalar>(xs: &[A]) -> Float64Array {
+    let components: Vec<f64> = xs
+        .iter()
+        .flat_map(|&x| {
+            let z: c64 = x.cast_lossy();
+            IntoIterator::into_iter([z.re, z.im]).take(A::COMPONENTS)
+        })
+        .collect();
+    Float64Array::from(components.as_slice())
+}
+
+/// Copy from a `Float64Array` of interleaved components,
+/// or `None` if its length is odd for complex types
+pub fn from_float64_array<A: Scalar>(array: &Float64Array) -> Option<Vec<A>> {
+    let components = array.to_vec();
+    let chunks = components.chunks_exact(A::COMPONENTS);
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+    let scalars = chunks
+        .map(|c| {
+            let im = if A::IS_COMPLEX { c[1] } else { 0.0 };
+            A::try_from_complex(A::complex(c[0], im)).unwrap()
+        })
+        .collect();
+    Some(scalars)
+}
@@ -0,0 +1,79 @@
+//! Byte views of scalars with [zerocopy](https://docs.rs/zerocopy)
+//!
+//! `f32` and `f64` implement the traits of zerocopy themselves. Since the orphan rule forbids
+//! implementing them for `c32` and `c64`, [RawC32] and [RawC64] provide complex numbers of the
+//! same layout, which convert into the types of this crate without copying.
+//!
+//! ```
+//! use cauchy::{c64, zerocopy::RawC64};
+//! use zerocopy::{FromBytes, IntoBytes};
+//!
+//! let bytes: Vec<u8> = [1.0_f64, 2.0, 3.0, 4.0].iter().flat_map(|x| x.to_ne_bytes()).collect();
+//! let raw = <[RawC64]>::ref_from_bytes(&bytes).unwrap();
+//! assert_eq!(RawC64::as_complex_slice(raw), &[c64::new(1.0, 2.0), c64::new(3.0, 4.0)]);
+//! assert_eq!(RawC64::from_complex_slice(&[c64::new(1.0, 2.0)]).as_bytes(), &bytes[..16]);
+//! ```
+
+use crate::{c32, c64, ScalarLayout};
+use ::zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+
+macro_rules! impl_raw_complex {
+    ($raw:ident, $real:ty, $complex:ty) => {
+        /// Complex number with the layout of
+        #[doc = concat!("`", stringify!($complex), "`")]
+        /// implementing the traits of zerocopy
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Default, FromBytes, IntoBytes, Immutable, KnownLayout,
+        )]
+        #[repr(C)]
+        pub struct $raw {
+            pub re: $real,
+            pub im: $real,
+        }
+
+        impl From<$complex> for $raw {
+            fn from(z: $complex) -> Self {
+                $raw { re: z.re, im: z.im }
+            }
+        }
+
+        impl From<$raw> for $complex {
+            fn from(z: $raw) -> Self {
+                <$complex>::new(z.re, z.im)
+            }
+        }
+
+        impl $raw {
+            /// View as complex numbers of this crate
+            pub fn as_complex_slice(xs: &[Self]) -> &[$complex] {
+                let parts = <[$real]>::ref_from_bytes(xs.as_bytes())
+                    .expect("components have the alignment of their parent");
+                <$real>::real_as_complex_slice(parts).expect("even number of components")
+            }
+
+            /// Mutable version of `as_complex_slice`
+            pub fn as_complex_slice_mut(xs: &mut [Self]) -> &mut [$complex] {
+                let parts = <[$real]>::mut_from_bytes(xs.as_mut_bytes())
+                    .expect("components have the alignment of their parent");
+                <$real>::real_as_complex_slice_mut(parts).expect("even number of components")
+            }
+
+            /// View complex numbers of this crate in the layout implementing zerocopy traits
+            pub fn from_complex_slice(zs: &[$complex]) -> &[Self] {
+                let parts = <$real>::complex_as_real_slice(zs);
+                <[Self]>::ref_from_bytes(parts.as_bytes())
+                    .expect("complex numbers have the size and alignment of the raw type")
+            }
+
+            /// Mutable version of `from_complex_slice`
+            pub fn from_complex_slice_mut(zs: &mut [$complex]) -> &mut [Self] {
+                let parts = <$real>::complex_as_real_slice_mut(zs);
+                <[Self]>::mut_from_bytes(parts.as_mut_bytes())
+                    .expect("complex numbers have the size and alignment of the raw type")
+            }
+        }
+    };
+}
+
+impl_raw_complex!(RawC32, f32, c32);
+impl_raw_complex!(RawC64, f64, c64);